@@ -0,0 +1,2 @@
+-- Camera linked to a subscription: its snapshot is attached to the notification
+ALTER TABLE subscriptions ADD COLUMN camera_entity_id TEXT;
@@ -0,0 +1,5 @@
+-- Telegram user -> HA device_tracker dev_id for location shares
+CREATE TABLE IF NOT EXISTS location_trackers (
+    user_id INTEGER PRIMARY KEY,
+    dev_id TEXT NOT NULL
+);
@@ -0,0 +1,6 @@
+CREATE TABLE IF NOT EXISTS update_notifications (
+    entity_id TEXT NOT NULL,
+    version TEXT NOT NULL,
+    notified_at DATETIME DEFAULT CURRENT_TIMESTAMP,
+    PRIMARY KEY (entity_id, version)
+);
@@ -0,0 +1,2 @@
+-- Order of pinned entities (favorites) on the Home screen and in the header
+ALTER TABLE pinned_headers ADD COLUMN position INTEGER NOT NULL DEFAULT 0;
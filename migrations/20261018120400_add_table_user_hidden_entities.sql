@@ -0,0 +1,7 @@
+-- Per-user visibility overrides. hidden_entities stays as the admin-defined default.
+CREATE TABLE IF NOT EXISTS user_hidden_entities (
+    user_id INTEGER NOT NULL,
+    entity_id TEXT NOT NULL,
+    hide INTEGER NOT NULL,
+    PRIMARY KEY (user_id, entity_id)
+);
@@ -0,0 +1,4 @@
+-- Room order in lists, custom emoji icon and the icon of the HA area (mdi:*)
+ALTER TABLE rooms ADD COLUMN position INTEGER NOT NULL DEFAULT 0;
+ALTER TABLE rooms ADD COLUMN icon TEXT;
+ALTER TABLE rooms ADD COLUMN ha_icon TEXT;
@@ -0,0 +1,4 @@
+-- Device order inside a room, optional section inside a room and compact sensor layout per room
+ALTER TABLE devices ADD COLUMN position INTEGER NOT NULL DEFAULT 0;
+ALTER TABLE devices ADD COLUMN group_name TEXT;
+ALTER TABLE rooms ADD COLUMN compact_sensors BOOLEAN NOT NULL DEFAULT 0;
@@ -0,0 +1,2 @@
+-- When the device disappeared from Home Assistant. NULL for devices archived before this column existed
+ALTER TABLE devices ADD COLUMN archived_at DATETIME;
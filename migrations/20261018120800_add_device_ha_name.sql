@@ -0,0 +1,2 @@
+-- Friendly name from Home Assistant, refreshed on every sync. Searchable after the device is renamed
+ALTER TABLE devices ADD COLUMN ha_name TEXT;
@@ -19,6 +19,8 @@ pub type MyDialogue = Dialogue<State, InMemStorage<State>>;
 pub enum Command {
     #[command(description = "Показать главное меню")]
     Start,
+    #[command(description = "Найти устройство: /find <текст>")]
+    Find(String),
 }
 
 /// Точка входа для команд. Соответствует Google Standard по очистке ресурсов.
@@ -45,6 +47,26 @@ pub async fn handle_command(
             let view = router(Payload::Home, user_id, config.clone()).await?;
             send_new_view(&bot, chat_id, user_id, view, config).await?;
         }
+        Command::Find(query) => {
+            dialogue.exit().await?;
+            if let Some(session) = config.sessions.get(&user_id) {
+                let _ = bot.delete_message(chat_id, MessageId(session.last_menu_id)).await;
+            }
+
+            let query = query.trim();
+            let payload = if query.is_empty() {
+                Payload::Search(crate::bot::router::SearchPayload::Prompt)
+            } else {
+                config.search_queries.insert(user_id, query.to_string());
+                Payload::Search(crate::bot::router::SearchPayload::Results)
+            };
+
+            let view = router(payload, user_id, config.clone()).await?;
+            if let Some(state) = view.next_state.clone() {
+                dialogue.update(state).await?;
+            }
+            send_new_view(&bot, chat_id, user_id, view, config).await?;
+        }
     }
 
     let _ = bot.delete_message(chat_id, msg.id).await;
@@ -107,6 +129,10 @@ async fn apply_view(
         dialogue.exit().await?;
     }
 
+    if let Some(loc) = &view.location {
+        bot.send_venue(chat_id, loc.latitude, loc.longitude, &loc.title, &loc.address).await?;
+    }
+
     update_view(bot, chat_id, message_id, user_id, view, config.clone()).await
 }
 
@@ -227,6 +253,301 @@ pub async fn handle_custom_interval(
     finalize_dialogue(bot, dialogue, msg, config, None).await
 }
 
+pub async fn handle_number_input(
+    bot: Bot,
+    msg: Message,
+    config: Arc<AppConfig>,
+    dialogue: MyDialogue,
+    (device_id, room_id): (i64, i64),
+) -> Result<()> {
+    use crate::core::devices::{self, DeviceAction, InteractionResult};
+    use crate::core::kinds::number::NumberRange;
+
+    let user_id = msg.from.as_ref().context("User missing")?.id.0;
+    let text = msg.text().unwrap_or("").trim().replace(',', ".");
+
+    let dev = crate::db::devices::get_device_by_id(device_id, &config.db).await?
+        .context("Device not found")?;
+    let entity = config.ha_client.fetch_states_by_ids(std::slice::from_ref(&dev.entity_id)).await?
+        .into_iter().next().context("HA state missing")?;
+    let range = NumberRange::from_entity(&entity);
+
+    let error = match text.parse::<f64>() {
+        // Молча округлять ввод нельзя: пользователь увидит не то значение, что ввел
+        Ok(value) if value.is_finite() && range.contains(value) && !range.is_on_step(value) => Some(format!(
+            "шаг значения {}, например {}.",
+            range.format(range.step),
+            range.format(range.snap(value))
+        )),
+        Ok(value) if value.is_finite() && range.contains(value) => {
+            match devices::handle_device_interaction(&config, user_id, device_id, DeviceAction::SetValue(value)).await? {
+                InteractionResult::Error { error } => Some(error),
+                _ => {
+                    let new_payload = Payload::Control(crate::bot::router::ControlPayload::DeviceControl {
+                        room: room_id,
+                        device: device_id,
+                    });
+                    return finalize_dialogue(bot, dialogue, msg, config, Some(new_payload)).await;
+                }
+            }
+        }
+        Ok(_) => Some(format!(
+            "значение должно быть в диапазоне {} … {}.",
+            range.format(range.min),
+            range.format(range.max)
+        )),
+        Err(_) => Some("введите число, например 21.5.".to_string()),
+    };
+
+    reject_input(bot, msg, error).await
+}
+
+pub async fn handle_text_input(
+    bot: Bot,
+    msg: Message,
+    config: Arc<AppConfig>,
+    dialogue: MyDialogue,
+    (device_id, room_id): (i64, i64),
+) -> Result<()> {
+    use crate::core::devices::{self, DeviceAction, InteractionResult};
+    use crate::core::kinds::text::TextLimits;
+
+    let user_id = msg.from.as_ref().context("User missing")?.id.0;
+    let text = msg.text().unwrap_or("").trim().to_string();
+
+    let dev = crate::db::devices::get_device_by_id(device_id, &config.db).await?
+        .context("Device not found")?;
+    let entity = config.ha_client.fetch_states_by_ids(std::slice::from_ref(&dev.entity_id)).await?
+        .into_iter().next().context("HA state missing")?;
+
+    let error = match TextLimits::from_entity(&entity).validate(&text) {
+        Ok(()) => {
+            match devices::handle_device_interaction(&config, user_id, device_id, DeviceAction::SetText(text)).await? {
+                InteractionResult::Error { error } => Some(error),
+                _ => {
+                    let new_payload = Payload::Control(crate::bot::router::ControlPayload::DeviceControl {
+                        room: room_id,
+                        device: device_id,
+                    });
+                    return finalize_dialogue(bot, dialogue, msg, config, Some(new_payload)).await;
+                }
+            }
+        }
+        Err(e) => Some(e),
+    };
+
+    reject_input(bot, msg, error).await
+}
+
+pub async fn handle_new_name(
+    bot: Bot,
+    msg: Message,
+    config: Arc<AppConfig>,
+    dialogue: MyDialogue,
+    (device_id, room_id): (i64, i64),
+) -> Result<()> {
+    let name = msg.text().unwrap_or("").trim().to_string();
+
+    if name.is_empty() || name.chars().count() > 64 {
+        return reject_input(bot, msg, Some("имя: от 1 до 64 символов.".to_string())).await;
+    }
+
+    let dev = crate::db::devices::get_device_by_id(device_id, &config.db).await?
+        .context("Device not found")?;
+    config.rename_device(device_id, &dev.entity_id, &name).await?;
+
+    let new_payload = Payload::Settings(crate::bot::router::SettingsPayload::DeviceDetail { room: room_id, device: device_id });
+    finalize_dialogue(bot, dialogue, msg, config, Some(new_payload)).await
+}
+
+pub async fn handle_state_alias_input(
+    bot: Bot,
+    msg: Message,
+    config: Arc<AppConfig>,
+    dialogue: MyDialogue,
+    (device_id, original_state, room_id): (i64, String, i64),
+) -> Result<()> {
+    let label = msg.text().unwrap_or("").trim().to_string();
+
+    if label.is_empty() || label.chars().count() > 32 {
+        return reject_input(bot, msg, Some("подпись: от 1 до 32 символов.".to_string())).await;
+    }
+
+    let dev = crate::db::devices::get_device_by_id(device_id, &config.db).await?
+        .context("Device not found")?;
+    let human_state = (label != "-").then_some(label.as_str());
+    config.set_state_alias(&dev.entity_id, &original_state, human_state).await?;
+
+    let new_payload = Payload::Settings(crate::bot::router::SettingsPayload::DeviceDetail { room: room_id, device: device_id });
+    finalize_dialogue(bot, dialogue, msg, config, Some(new_payload)).await
+}
+
+pub async fn handle_group_name_input(
+    bot: Bot,
+    msg: Message,
+    config: Arc<AppConfig>,
+    dialogue: MyDialogue,
+    (device_id, room_id): (i64, i64),
+) -> Result<()> {
+    let group = msg.text().unwrap_or("").trim().to_string();
+
+    if group.is_empty() || group.chars().count() > 32 {
+        return reject_input(bot, msg, Some("название группы: от 1 до 32 символов.".to_string())).await;
+    }
+
+    crate::db::devices::set_device_group(device_id, Some(&group), &config.db).await?;
+
+    let new_payload = Payload::Settings(crate::bot::router::SettingsPayload::DeviceDetail { room: room_id, device: device_id });
+    finalize_dialogue(bot, dialogue, msg, config, Some(new_payload)).await
+}
+
+pub async fn handle_search_input(
+    bot: Bot,
+    msg: Message,
+    config: Arc<AppConfig>,
+    dialogue: MyDialogue,
+) -> Result<()> {
+    let user_id = msg.from.as_ref().context("User missing")?.id.0;
+    let query = msg.text().unwrap_or("").trim().to_string();
+
+    if query.is_empty() || query.chars().count() > 64 {
+        return reject_input(bot, msg, Some("запрос: от 1 до 64 символов.".to_string())).await;
+    }
+
+    config.search_queries.insert(user_id, query);
+
+    let new_payload = Payload::Search(crate::bot::router::SearchPayload::Results);
+    finalize_dialogue(bot, dialogue, msg, config, Some(new_payload)).await
+}
+
+pub async fn handle_tracker_id_input(
+    bot: Bot,
+    msg: Message,
+    config: Arc<AppConfig>,
+    dialogue: MyDialogue,
+) -> Result<()> {
+    let user_id = msg.from.as_ref().context("User missing")?.id.0;
+    let dev_id = msg.text().unwrap_or("").trim().to_lowercase();
+
+    if !crate::core::location::is_valid_dev_id(&dev_id) {
+        let error = "допустимы латиница, цифры и _, до 64 символов.".to_string();
+        return reject_input(bot, msg, Some(error)).await;
+    }
+
+    crate::db::location_trackers::set_dev_id(user_id, &dev_id, &config.db).await?;
+
+    let new_payload = Payload::Settings(crate::bot::router::SettingsPayload::LocationTracker);
+    finalize_dialogue(bot, dialogue, msg, config, Some(new_payload)).await
+}
+
+pub async fn handle_room_name_input(
+    bot: Bot,
+    msg: Message,
+    config: Arc<AppConfig>,
+    dialogue: MyDialogue,
+    room_id: i64,
+) -> Result<()> {
+    let name = msg.text().unwrap_or("").trim().to_string();
+
+    if name.is_empty() || name.chars().count() > 64 {
+        return reject_input(bot, msg, Some("название: от 1 до 64 символов.".to_string())).await;
+    }
+
+    let room = crate::db::rooms::get_room_by_id(room_id, &config.db).await?
+        .context("Room not found")?;
+    crate::db::rooms::upsert_room(&room.area, Some(name), room.hide, &config.db).await?;
+
+    let new_payload = Payload::Settings(crate::bot::router::SettingsPayload::RoomOptions { room: room_id });
+    finalize_dialogue(bot, dialogue, msg, config, Some(new_payload)).await
+}
+
+pub async fn handle_room_icon_input(
+    bot: Bot,
+    msg: Message,
+    config: Arc<AppConfig>,
+    dialogue: MyDialogue,
+    room_id: i64,
+) -> Result<()> {
+    let icon = msg.text().unwrap_or("").trim().to_string();
+
+    if !crate::core::presentation::StateFormatter::is_valid_room_icon(&icon) {
+        return reject_input(bot, msg, Some("нужно одно эмодзи, без букв и цифр.".to_string())).await;
+    }
+
+    crate::db::rooms::set_room_icon(room_id, Some(&icon), &config.db).await?;
+
+    let new_payload = Payload::Settings(crate::bot::router::SettingsPayload::RoomOptions { room: room_id });
+    finalize_dialogue(bot, dialogue, msg, config, Some(new_payload)).await
+}
+
+pub async fn handle_todo_item_input(
+    bot: Bot,
+    msg: Message,
+    config: Arc<AppConfig>,
+    dialogue: MyDialogue,
+    (list, entity_id): (u32, String),
+) -> Result<()> {
+    let summary = msg.text().unwrap_or("").trim().to_string();
+
+    if summary.is_empty() || summary.chars().count() > 255 {
+        return reject_input(bot, msg, Some("текст пункта: от 1 до 255 символов.".to_string())).await;
+    }
+
+    if let Err(e) = crate::core::calendar::add_todo_item(&config.ha_client, &entity_id, &summary).await {
+        log::error!("Failed to add todo item to {}: {}", entity_id, e);
+        return reject_input(bot, msg, Some("не удалось добавить пункт в Home Assistant.".to_string())).await;
+    }
+
+    let new_payload = Payload::Todo(crate::bot::router::TodoPayload::List { list });
+    finalize_dialogue(bot, dialogue, msg, config, Some(new_payload)).await
+}
+
+/// Геопозиция (в том числе обновления live-трансляции) передается в HA как device_tracker.
+pub async fn handle_location(
+    bot: Bot,
+    msg: Message,
+    config: Arc<AppConfig>,
+    location: teloxide::types::Location,
+) -> Result<()> {
+    let user_id = msg.from.as_ref().context("User missing")?.id.0;
+    // Первичное сообщение с геопозицией; правки приходят как EditedMessage
+    let is_first_share = msg.edit_date().is_none();
+
+    let reply = match crate::core::location::report_location(
+        &config,
+        user_id,
+        location.latitude,
+        location.longitude,
+        location.horizontal_accuracy,
+    ).await {
+        Ok(true) => "📍 Геопозиция передана в Home Assistant",
+        Ok(false) => "📍 Трекер не настроен: Настройки → Трекер геопозиции",
+        Err(e) => {
+            log::error!("Failed to report location for user {}: {}", user_id, e);
+            "⚠️ Не удалось передать геопозицию в Home Assistant"
+        }
+    };
+
+    // Сообщение с геопозицией не удаляем - это остановит трансляцию
+    if is_first_share {
+        let info = bot.send_message(msg.chat.id, reply).await?;
+        crate::bot::utils::spawn_delayed_delete(bot, msg.chat.id, info.id, 5);
+    }
+
+    Ok(())
+}
+
+/// Ввод невалиден - остаемся в диалоге, чтобы пользователь мог повторить попытку.
+async fn reject_input(bot: Bot, msg: Message, error: Option<String>) -> Result<()> {
+    let _ = bot.delete_message(msg.chat.id, msg.id).await;
+    if let Some(e) = error {
+        let err_msg = bot.send_message(msg.chat.id, format!("⚠️ Ошибка: {}", e)).await?;
+        crate::bot::utils::spawn_delayed_delete(bot, msg.chat.id, err_msg.id, 5);
+    }
+
+    Ok(())
+}
+
 /// Завершает диалог, очищает чат и обновляет интерфейс.
 /// Соответствует Google Style Guide: инкапсуляция побочных эффектов и атомарная работа с памятью.
 async fn finalize_dialogue(
@@ -0,0 +1,155 @@
+use std::sync::Arc;
+
+use anyhow::{Context, Result};
+use teloxide::prelude::*;
+use teloxide::types::{
+    InlineKeyboardButton, InlineKeyboardMarkup, InlineQueryResult, InlineQueryResultArticle,
+    InputMessageContent, InputMessageContentText,
+};
+
+use crate::bot::router::{DeviceCmd, InlinePayload, Payload};
+use crate::core::devices::{self, InteractionResult};
+use crate::core::types::Device;
+use crate::core::{kinds, search};
+use crate::ha::models::Entity;
+use crate::models::AppConfig;
+
+/// Результатов в ответе на inline-запрос (Telegram допускает до 50).
+const MAX_INLINE_RESULTS: usize = 20;
+
+/// `@bot кухня` в любом чате: найденные устройства с текущим состоянием.
+/// Доступ проверяет общий фильтр авторизации в `schema`.
+pub async fn handle_inline_query(bot: Bot, q: InlineQuery, config: Arc<AppConfig>) -> Result<()> {
+    let query = q.query.trim();
+
+    let hits = if query.is_empty() {
+        Vec::new()
+    } else {
+        search::find_devices(&config, q.from.id.0, query).await?
+    };
+
+    let results: Vec<InlineQueryResult> = hits.iter()
+        .take(MAX_INLINE_RESULTS)
+        .map(|hit| {
+            let (text, kb) = render_card(&config, &hit.device, &hit.entity, &hit.room_name);
+            let alias = hit.device.alias.as_deref().unwrap_or(&hit.device.entity_id);
+            let title = match hit.room_name.is_empty() {
+                true => alias.to_string(),
+                false => format!("{} • {}", hit.room_name, alias),
+            };
+
+            InlineQueryResultArticle::new(
+                hit.device.id.to_string(),
+                title,
+                InputMessageContent::Text(InputMessageContentText::new(text)),
+            )
+                .description(state_text(&config, &hit.entity))
+                .reply_markup(kb)
+                .into()
+        })
+        .collect();
+
+    // Результаты зависят от видимости устройств пользователя, поэтому без общего кэша
+    bot.answer_inline_query(q.id, results)
+        .cache_time(0)
+        .is_personal(true)
+        .await?;
+    Ok(())
+}
+
+/// Нажатие кнопки в сообщении, отправленном через inline-режим.
+pub async fn handle_inline_callback(bot: Bot, q: CallbackQuery, config: Arc<AppConfig>) -> Result<()> {
+    let inline_message_id = q.inline_message_id.clone().context("Inline message id missing")?;
+    let data = q.data.as_deref().context("No callback data")?;
+
+    let Ok(Payload::Inline(payload)) = Payload::from_string(data) else {
+        bot.answer_callback_query(q.id).text("Кнопка устарела").await?;
+        return Ok(());
+    };
+
+    let device_id = match &payload {
+        InlinePayload::Cmd { device, .. } | InlinePayload::Refresh { device } => *device,
+    };
+    let Some(dev) = crate::db::devices::get_device_by_id(device_id, &config.db).await? else {
+        bot.answer_callback_query(q.id).text("Устройство не найдено").await?;
+        return Ok(());
+    };
+    // Карточку в общем чате может нажать любой участник: те же правила видимости, что и в поиске
+    if crate::db::subscriptions::is_hidden_for(q.from.id.0, &dev.entity_id, &config.db).await? {
+        bot.answer_callback_query(q.id).text("Устройство недоступно").show_alert(true).await?;
+        return Ok(());
+    }
+
+    let mut alert = None;
+    if let InlinePayload::Cmd { cmd, .. } = payload {
+        let domain = dev.entity_id.split('.').next().unwrap_or("");
+        if kinds::for_domain(domain).supports(&cmd) {
+            if let InteractionResult::Error { error } =
+                devices::handle_device_interaction(&config, q.from.id.0, device_id, cmd.into()).await?
+            {
+                alert = Some(error);
+            }
+        }
+    }
+
+    let entity = config.ha_client.fetch_states_by_ids(std::slice::from_ref(&dev.entity_id)).await?
+        .into_iter().next().context("HA state missing")?;
+    let room_name = match crate::db::rooms::get_room_by_id(dev.room_id, &config.db).await? {
+        Some(room) => room.alias.unwrap_or(room.area),
+        None => String::new(),
+    };
+
+    let answer = bot.answer_callback_query(q.id);
+    match alert {
+        Some(text) => answer.text(text).show_alert(true).await?,
+        None => answer.await?,
+    };
+
+    let (text, kb) = render_card(&config, &dev, &entity, &room_name);
+    // Telegram отвечает ошибкой, если текст и кнопки не изменились - это не сбой
+    let _ = bot.edit_message_text_inline(inline_message_id, text).reply_markup(kb).await;
+    Ok(())
+}
+
+/// Нажатие кнопки пользователем без доступа: отвечаем, иначе у него крутится индикатор загрузки.
+pub async fn handle_denied_callback(bot: Bot, q: CallbackQuery) -> Result<()> {
+    bot.answer_callback_query(q.id).text("⛔ Нет доступа").show_alert(true).await?;
+    Ok(())
+}
+
+/// Текст и кнопки карточки устройства для чужого чата.
+fn render_card(config: &AppConfig, dev: &Device, entity: &Entity, room_name: &str) -> (String, InlineKeyboardMarkup) {
+    let alias = dev.alias.as_deref().unwrap_or(&dev.entity_id);
+    let domain = kinds::entity_domain(entity);
+    let class = entity.device_class.as_deref().unwrap_or("");
+    let icon = crate::core::presentation::StateFormatter::get_icon(domain, class, &entity.state);
+
+    let prefix = match room_name.is_empty() {
+        true => String::new(),
+        false => format!("{} • ", room_name),
+    };
+    let text = format!("{} {}{}: {}", icon, prefix, alias, state_text(config, entity));
+
+    let button = |label: &str, payload: InlinePayload| {
+        InlineKeyboardButton::callback(label, Payload::Inline(payload).to_string())
+    };
+
+    let kind = kinds::for_entity(entity);
+    let mut rows = vec![];
+    if kind.supports(&DeviceCmd::TurnOn) && kind.supports(&DeviceCmd::TurnOff) {
+        rows.push(vec![
+            button("▶️ Включить", InlinePayload::Cmd { device: dev.id, cmd: DeviceCmd::TurnOn }),
+            button("⏹ Выключить", InlinePayload::Cmd { device: dev.id, cmd: DeviceCmd::TurnOff }),
+        ]);
+    } else if let Some(cmd) = kind.room_cmd().filter(|cmd| kind.supports(cmd)) {
+        rows.push(vec![button("⏯ Выполнить", InlinePayload::Cmd { device: dev.id, cmd })]);
+    }
+    rows.push(vec![button("🔄 Обновить", InlinePayload::Refresh { device: dev.id })]);
+
+    (text, InlineKeyboardMarkup::new(rows))
+}
+
+fn state_text(config: &AppConfig, entity: &Entity) -> String {
+    let class = entity.device_class.as_deref().unwrap_or("");
+    config.format_state(&entity.entity_id, class, &entity.state)
+}
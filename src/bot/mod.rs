@@ -1,9 +1,10 @@
 pub(crate) mod handlers;
-mod utils;
+pub(crate) mod utils;
 pub(crate) mod notification;
 pub(crate) mod models;
-mod screens;
+pub(crate) mod screens;
 pub(crate) mod router;
+pub(crate) mod inline;
 
 use std::sync::Arc;
 use teloxide::{
@@ -21,6 +22,17 @@ pub fn init(token: String) -> Bot {
     Bot::new(token)
 }
 
+/// Доступ к боту: root или пользователь из белого списка.
+async fn is_authorized(user_id: u64, config: &AppConfig) -> bool {
+    // Root пользователь имеет безусловный доступ.
+    if config.root_user == user_id {
+        return true;
+    }
+
+    // Проверка наличия пользователя в белом списке БД.
+    db::user_exists(user_id, &config.db).await
+}
+
 /// Строит дерево обработки обновлений (Update Hierarchy).
 /// Соответствует Google Standard: разделение ответственности между уровнями фильтрации.
 pub fn schema() -> UpdateHandler<anyhow::Error> {
@@ -30,17 +42,17 @@ pub fn schema() -> UpdateHandler<anyhow::Error> {
             return false;
         };
 
-        let user_id = user.id.0;
-
-        // Root пользователь имеет безусловный доступ.
-        if config.root_user == user_id {
-            return true;
-        }
-
-        // Проверка наличия пользователя в белом списке БД.
-        db::user_exists(user_id, &config.db).await
+        is_authorized(user.id.0, &config).await
     });
 
+    // 1a. Нажатия без доступа (например, карточка inline-режима в общем чате) не отбрасываем
+    // молча: без ответа у пользователя бесконечно крутится индикатор на кнопке.
+    let denied_callback_handler = Update::filter_callback_query()
+        .filter_async(|q: CallbackQuery, config: Arc<AppConfig>| async move {
+            !is_authorized(q.from.id.0, &config).await
+        })
+        .endpoint(inline::handle_denied_callback);
+
     // 2. Ветка команд: обрабатывает системные команды (начинающиеся с /).
     let command_handler = Update::filter_message()
         .filter_command::<handlers::Command>()
@@ -50,17 +62,44 @@ pub fn schema() -> UpdateHandler<anyhow::Error> {
     let callback_handler = Update::filter_callback_query()
         .endpoint(handlers::handle_callback);
 
-    // 4. Ветка Диалогов: обрабатывает текстовый ввод в зависимости от состояния.
+    // 3a. Inline-режим: `@bot кухня` в любом чате и кнопки отправленных так карточек.
+    // У этих обновлений нет чата, поэтому они обрабатываются до входа в диалог:
+    // `enter_dialogue` отбрасывает обновления без chat_id.
+    let inline_handler = dptree::entry()
+        .chain(auth_filter.clone())
+        .branch(Update::filter_inline_query()
+            .endpoint(inline::handle_inline_query))
+        .branch(Update::filter_callback_query()
+            .filter(|q: CallbackQuery| q.inline_message_id.is_some())
+            .endpoint(inline::handle_inline_callback));
+
+    // 4. Ветка Геопозиции: разовая отправка и обновления live-трансляции.
+    let location_handler = dptree::entry()
+        .branch(Update::filter_message()
+            .filter_map(|msg: Message| msg.location().cloned())
+            .endpoint(handlers::handle_location))
+        .branch(Update::filter_edited_message()
+            .filter_map(|msg: Message| msg.location().cloned())
+            .endpoint(handlers::handle_location));
+
+    // 5. Ветка Диалогов: обрабатывает текстовый ввод в зависимости от состояния.
     let message_dialogues = Update::filter_message()
         // Игнорируем команды, чтобы они не перехватывались диалогом.
         .filter(|msg: Message| msg.text().map_or(true, |t| !t.starts_with('/')))
-        // .branch(
-        //     dptree::filter_map(|state: State| match state {
-        //         State::WaitingForName { device_id, room_id } => Some((device_id, room_id)),
-        //         _ => None,
-        //     })
-        //         .endpoint(handlers::handle_new_name),
-        // )
+        .branch(
+            dptree::filter_map(|state: State| match state {
+                State::WaitingForName { device_id, room_id } => Some((device_id, room_id)),
+                _ => None,
+            })
+                .endpoint(handlers::handle_new_name),
+        )
+        .branch(
+            dptree::filter_map(|state: State| match state {
+                State::WaitingForStateAlias { device_id, original_state, room_id } => Some((device_id, original_state, room_id)),
+                _ => None,
+            })
+                .endpoint(handlers::handle_state_alias_input),
+        )
         .branch(
             dptree::filter_map(|state: State| match state {
                 State::WaitingForGraphInterval { device_id, room_id } => Some((device_id, room_id)),
@@ -68,6 +107,56 @@ pub fn schema() -> UpdateHandler<anyhow::Error> {
             })
                 .endpoint(handlers::handle_custom_interval),
         )
+        .branch(
+            dptree::filter_map(|state: State| match state {
+                State::WaitingForNumber { device_id, room_id } => Some((device_id, room_id)),
+                _ => None,
+            })
+                .endpoint(handlers::handle_number_input),
+        )
+        .branch(
+            dptree::filter_map(|state: State| match state {
+                State::WaitingForText { device_id, room_id } => Some((device_id, room_id)),
+                _ => None,
+            })
+                .endpoint(handlers::handle_text_input),
+        )
+        .branch(
+            dptree::filter(|state: State| matches!(state, State::WaitingForSearch))
+                .endpoint(handlers::handle_search_input),
+        )
+        .branch(
+            dptree::filter(|state: State| matches!(state, State::WaitingForTrackerId))
+                .endpoint(handlers::handle_tracker_id_input),
+        )
+        .branch(
+            dptree::filter_map(|state: State| match state {
+                State::WaitingForTodoItem { list, entity_id } => Some((list, entity_id)),
+                _ => None,
+            })
+                .endpoint(handlers::handle_todo_item_input),
+        )
+        .branch(
+            dptree::filter_map(|state: State| match state {
+                State::WaitingForRoomName { room_id } => Some(room_id),
+                _ => None,
+            })
+                .endpoint(handlers::handle_room_name_input),
+        )
+        .branch(
+            dptree::filter_map(|state: State| match state {
+                State::WaitingForRoomIcon { room_id } => Some(room_id),
+                _ => None,
+            })
+                .endpoint(handlers::handle_room_icon_input),
+        )
+        .branch(
+            dptree::filter_map(|state: State| match state {
+                State::WaitingForGroupName { device_id, room_id } => Some((device_id, room_id)),
+                _ => None,
+            })
+                .endpoint(handlers::handle_group_name_input),
+        )
         // Поглощаем сообщения в состоянии Idle, чтобы они не падали в Unhandled Update.
         .branch(
             dptree::filter(|state: State| matches!(state, State::Idle))
@@ -79,13 +168,16 @@ pub fn schema() -> UpdateHandler<anyhow::Error> {
                 })
         );
 
-    // 5. Итоговое дерево (Main Entry Point)
+    // 6. Итоговое дерево (Main Entry Point)
     dptree::entry()
+        .branch(denied_callback_handler)
+        .branch(inline_handler)
         // Инъекция хранилища состояний диалогов.
         .enter_dialogue::<Update, InMemStorage<State>, State>()
         .chain(auth_filter)
         .branch(command_handler)
         .branch(callback_handler)
+        .branch(location_handler)
         .branch(message_dialogues)
         .endpoint(|update: Update, state: State| async move {
             let user_id = update.from().map(|u| u.id.0).unwrap_or(0);
@@ -108,4 +200,55 @@ pub fn schema() -> UpdateHandler<anyhow::Error> {
 
             Ok::<(), anyhow::Error>(())
         })
-}
\ No newline at end of file
+}
+
+#[cfg(test)]
+mod tests {
+    use std::ops::ControlFlow;
+
+    use dashmap::DashMap;
+    use super::*;
+
+    const ROOT_USER: u64 = 42;
+
+    /// Обновление доходит до обработчика, а не отбрасывается деревом.
+    /// API Telegram недоступен, поэтому обработчик завершается ошибкой ответа - это `Break`.
+    async fn dispatch(update: serde_json::Value) -> ControlFlow<anyhow::Result<()>, dptree::di::DependencyMap> {
+        let bot = Bot::new("0:test").set_api_url(reqwest::Url::parse("http://127.0.0.1:9").unwrap());
+        let config = Arc::new(AppConfig {
+            ha_client: Arc::new(crate::ha::init("http://127.0.0.1:9".into(), String::new())),
+            db: sqlx::SqlitePool::connect_lazy("sqlite::memory:").unwrap(),
+            root_user: ROOT_USER,
+            delete_notification_messages_timeout_s: 5,
+            ttl_notifications: 1,
+            background_maintenance_interval_s: 15,
+            sessions: DashMap::new(),
+            name_aliases: DashMap::new(),
+            state_aliases: DashMap::new(),
+            video: crate::video_engine::VideoProcessor::new().0,
+            confirmations: crate::core::confirmations::StateConfirmations::new(),
+            undo: crate::core::undo::UndoStore::default(),
+            search_queries: DashMap::new(),
+        });
+        let update: Update = serde_json::from_str(&update.to_string()).unwrap();
+
+        schema().dispatch(dptree::deps![bot, config, InMemStorage::<State>::new(), update]).await
+    }
+
+    #[tokio::test]
+    async fn test_inline_updates_reach_handlers() {
+        let from = serde_json::json!({ "id": ROOT_USER, "is_bot": false, "first_name": "Root" });
+
+        let query = dispatch(serde_json::json!({
+            "update_id": 1,
+            "inline_query": { "id": "1", "from": from, "query": "", "offset": "" },
+        })).await;
+        assert!(matches!(query, ControlFlow::Break(_)), "InlineQuery dropped by the schema");
+
+        let callback = dispatch(serde_json::json!({
+            "update_id": 2,
+            "callback_query": { "id": "2", "from": from, "inline_message_id": "abc", "chat_instance": "1", "data": "-" },
+        })).await;
+        assert!(matches!(callback, ControlFlow::Break(_)), "Inline card callback dropped by the schema");
+    }
+}
@@ -14,6 +14,16 @@ pub struct View {
     pub next_state: Option<State>,
     pub alert: Option<String>,
     pub image: Option<Vec<u8>>,
+    /// Точка на карте, отправляется отдельным сообщением при нажатии кнопки.
+    pub location: Option<LocationShare>,
+}
+
+#[derive(Clone, Serialize, Deserialize, Debug, Default)]
+pub struct LocationShare {
+    pub latitude: f64,
+    pub longitude: f64,
+    pub title: String,
+    pub address: String,
 }
 
 impl View {
@@ -1,7 +1,7 @@
 use std::sync::Arc;
 use anyhow::Result;
 use teloxide::prelude::*;
-use teloxide::types::ChatId;
+use teloxide::types::{ChatId, InlineKeyboardMarkup, InputFile, ParseMode};
 use crate::models::{AppConfig, NotificationData};
 
 
@@ -34,6 +34,37 @@ pub async fn send_notification_text_to_recipient(bot: Bot,
     });
 }
 
+/// Уведомление со снимком камеры. Не удаляется автоматически - это "улика".
+pub async fn send_notification_photo_to_recipient(bot: Bot,
+                                                  recipient: i64,
+                                                  caption: String,
+                                                  image: Vec<u8>) {
+    tokio::spawn(async move {
+        if let Err(e) = bot.send_photo(ChatId(recipient), InputFile::memory(image))
+            .caption(caption)
+            .parse_mode(ParseMode::MarkdownV2)
+            .await
+        {
+            log::error!("Failed to send snapshot notification to {}: {}", recipient, e);
+        }
+    });
+}
+
+/// Уведомление админам с кнопками. Не удаляется автоматически - требует реакции.
+pub async fn send_admin_notification(bot: Bot,
+                                     recipients: Vec<i64>,
+                                     text: String,
+                                     kb: InlineKeyboardMarkup) {
+    for recipient in recipients {
+        if let Err(e) = bot.send_message(ChatId(recipient), text.as_str())
+            .reply_markup(kb.clone())
+            .await
+        {
+            log::error!("Failed to send admin notification to {}: {}", recipient, e);
+        }
+    }
+}
+
 pub async fn send_notification(bot: Bot, config: Arc<AppConfig>, data: NotificationData) -> Result<()> {
     for user_id in data.recipients {
         let m = data.human_state.clone();
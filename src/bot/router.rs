@@ -17,6 +17,8 @@ pub struct RenderContext {
     pub config: Arc<AppConfig>,
     pub notifications: Vec<HeaderItem>,
     pub is_admin: bool,
+    /// Страница списочного экрана из `Payload::Page` (0 - первая).
+    pub page: u8,
 }
 
 #[derive(Clone, Serialize, Deserialize, Debug, Default)]
@@ -29,6 +31,14 @@ pub enum State {
     BackupDb { path: String },
     AddUser { user_id: i64 },
     DeleteUser { user_id: i64 },
+    WaitingForNumber { device_id: i64, room_id: i64 },
+    WaitingForText { device_id: i64, room_id: i64 },
+    WaitingForTrackerId,
+    WaitingForTodoItem { list: u32, entity_id: String },
+    WaitingForRoomName { room_id: i64 },
+    WaitingForRoomIcon { room_id: i64 },
+    WaitingForGroupName { device_id: i64, room_id: i64 },
+    WaitingForSearch,
 }
 
 impl State {
@@ -41,6 +51,10 @@ impl State {
                 State::WaitingForName { device_id, room_id },
             InputIntent::SetStateAlias { original_state, .. } =>
                 State::WaitingForStateAlias { device_id, original_state, room_id },
+            InputIntent::SetNumericValue { .. } =>
+                State::WaitingForNumber { device_id, room_id },
+            InputIntent::SetText { .. } =>
+                State::WaitingForText { device_id, room_id },
         }
     }
 }
@@ -56,6 +70,10 @@ impl From<devices::InputIntent> for State {
                 State::WaitingForStateAlias { device_id, original_state, room_id },
             InputIntent::DefineGraphInterval { device_id, room_id } =>
                 State::WaitingForGraphInterval { device_id, room_id },
+            InputIntent::SetNumericValue { device_id, room_id } =>
+                State::WaitingForNumber { device_id, room_id },
+            InputIntent::SetText { device_id, room_id } =>
+                State::WaitingForText { device_id, room_id },
         }
     }
 }
@@ -73,6 +91,14 @@ pub enum DeviceCmd {
         o: i32,
     },
     EnterManualInput,
+    SetValue(f64),
+    SelectOption(u16),
+    /// Страница списка в карточке устройства (например, опции select).
+    Page(u8),
+    /// Черновик даты/времени в пикере (naive timestamp), без записи в HA.
+    DraftDateTime(i64),
+    SetDateTime(i64),
+    RecordClip,
 }
 
 impl From<DeviceCmd> for devices::DeviceAction {
@@ -86,6 +112,12 @@ impl From<DeviceCmd> for devices::DeviceAction {
             DeviceCmd::SetTemp(v) => DeviceAction::SetTemperature(v),
             DeviceCmd::ShowChart { h, o } => DeviceAction::GenerateChart(ChartParams { period_hours: h, offset_hours: o }),
             DeviceCmd::EnterManualInput => DeviceAction::EnterManualInput,
+            DeviceCmd::SetValue(v) => DeviceAction::SetValue(v),
+            DeviceCmd::SelectOption(i) => DeviceAction::SelectOption(i),
+            DeviceCmd::Page(_) | DeviceCmd::DraftDateTime(_) => DeviceAction::OpenDetail,
+            DeviceCmd::SetDateTime(ts) => DeviceAction::SetDateTime(ts),
+            // Запись клипа ставится в очередь роутером (нужен получатель), здесь только карточка
+            DeviceCmd::RecordClip => DeviceAction::OpenDetail,
         }
     }
 }
@@ -97,7 +129,33 @@ pub enum Payload {
     Control(ControlPayload),
     Settings(SettingsPayload),
     Admin(AdminPayload),
-    InDev
+    InDev,
+    Presence(PresencePayload),
+    Weather(WeatherPayload),
+    Calendar(CalendarPayload),
+    Todo(TodoPayload),
+    /// Отмена последнего действия пользователя (см. `core::undo`).
+    Undo,
+    /// Страница списочного экрана `of` (см. `screens::pagination`). Первая страница не оборачивается.
+    Page { of: Box<Payload>, page: u8 },
+    Search(SearchPayload),
+    /// Кнопки сообщений, отправленных через inline-режим (см. `bot::inline`), мимо роутера.
+    Inline(InlinePayload),
+}
+
+#[derive(Serialize, Deserialize, Debug, Clone, PartialEq)]
+pub enum InlinePayload {
+    Cmd { device: i64, cmd: DeviceCmd },
+    Refresh { device: i64 },
+}
+
+/// Поиск устройств. Сам запрос хранится в `AppConfig::search_queries`.
+#[derive(Serialize, Deserialize, Debug, Clone, PartialEq)]
+pub enum SearchPayload {
+    Prompt,
+    Results,
+    /// Быстрое действие над найденным устройством, после него - снова результаты.
+    Action { device: i64 },
 }
 
 #[derive(Serialize, Deserialize, Debug, Clone, PartialEq)]
@@ -115,6 +173,22 @@ pub enum ControlPayload {
         device: i64,
         cmd: DeviceCmd,
     },
+    RoomBulk {
+        room: i64,
+        action: BulkAction,
+    },
+    /// «Ухожу из дома»: выключить все во всех комнатах, сначала с подтверждением.
+    LeaveHome { confirmed: bool },
+    /// Быстрое действие избранного устройства с главного экрана.
+    FavoriteAction { device: i64 },
+}
+
+/// Групповые действия над видимыми устройствами комнаты или дома.
+#[derive(Serialize, Deserialize, Debug, Clone, Copy, PartialEq)]
+pub enum BulkAction {
+    LightsOff,
+    AllOff,
+    AllOn,
 }
 
 #[derive(Serialize, Deserialize, Debug, Clone, PartialEq)]
@@ -134,6 +208,92 @@ pub enum SettingsPayload {
         room: i64,
         device: i64
     },
+    PickCamera {
+        room: i64,
+        device: i64
+    },
+    /// `camera` - id устройства камеры, 0 - отвязать.
+    LinkCamera {
+        room: i64,
+        device: i64,
+        camera: i64
+    },
+    LocationTracker,
+    EditLocationTracker,
+    ClearLocationTracker,
+    ToggleFavorite {
+        room: i64,
+        device: i64
+    },
+    Favorites,
+    /// `key` - `core::short_key` от entity_id закрепленной сущности.
+    MoveFavorite { key: u32, up: bool },
+    RemoveFavorite { key: u32 },
+    /// Видимость по умолчанию для всех пользователей (только для админа).
+    ToggleHideGlobal {
+        room: i64,
+        device: i64
+    },
+    /// Параметры комнаты общие для всех, поэтому доступны только админу.
+    RoomOptions { room: i64 },
+    RenameRoom { room: i64 },
+    ToggleRoomHide { room: i64 },
+    PickRoomIcon { room: i64 },
+    /// `icon` - индекс в `room_settings::PRESET_ICONS`, `RESET_ICON` - вернуть иконку по умолчанию.
+    SetRoomIcon { room: i64, icon: u8 },
+    EnterRoomIcon { room: i64 },
+    MoveRoom { room: i64, up: bool },
+    /// Подпись текущего состояния устройства.
+    EditStateAlias {
+        room: i64,
+        device: i64
+    },
+    MoveDevice { room: i64, device: i64, up: bool },
+    PickDeviceGroup {
+        room: i64,
+        device: i64
+    },
+    /// `group` - индекс в `db::devices::get_room_groups`, `NO_GROUP` - убрать из группы.
+    SetDeviceGroup {
+        room: i64,
+        device: i64,
+        group: u8
+    },
+    NewDeviceGroup {
+        room: i64,
+        device: i64
+    },
+    ToggleCompactSensors { room: i64 },
+}
+
+#[derive(Serialize, Deserialize, Debug, Clone, PartialEq)]
+pub enum PresencePayload {
+    List,
+    /// `person` - `core::short_key` от entity_id person-сущности.
+    Locate { person: u32 },
+}
+
+/// `entity` - `core::short_key` от entity_id weather-сущности, `0` - первая по entity_id.
+#[derive(Serialize, Deserialize, Debug, Clone, PartialEq)]
+pub enum WeatherPayload {
+    Show { entity: u32, daily: bool },
+    TogglePin { entity: u32, daily: bool },
+}
+
+#[derive(Serialize, Deserialize, Debug, Clone, PartialEq)]
+pub enum CalendarPayload {
+    /// Сегодня или ближайшие 7 дней.
+    Upcoming { week: bool },
+}
+
+/// `list` - `core::short_key` от entity_id todo-сущности.
+#[derive(Serialize, Deserialize, Debug, Clone, PartialEq)]
+pub enum TodoPayload {
+    Lists,
+    List { list: u32 },
+    /// `item` - ключ пункта (`core::short_key` от uid).
+    Check { list: u32, item: u32 },
+    Add { list: u32 },
 }
 
 #[derive(Serialize, Deserialize, Debug, Clone, PartialEq)]
@@ -142,9 +302,57 @@ pub enum AdminPayload {
     ListUsers,
     AddUser { id: u32 },
     DeleteUser { id: u32 },
+    Automations,
+    /// `key` - `core::short_key` от entity_id автоматизации.
+    Automation { key: u32 },
+    AutomationCmd { key: u32, cmd: AutomationCmd },
+    Updates,
+    /// `key` - `core::short_key` от entity_id update-сущности.
+    Update { key: u32 },
+    ConfirmInstall { key: u32 },
+    Install { key: u32 },
+    HaNotifications,
+    /// `key` - `core::short_key` от notification_id.
+    DismissHaNotification { key: u32 },
+    ArchivedDevices,
+    ArchivedDevice { device: i64 },
+    RestoreDevice { device: i64 },
+    ConfirmPurgeDevice { device: i64 },
+    PurgeDevice { device: i64 },
+    /// Выбор активного устройства, на которое переносятся настройки архивного.
+    PickMigrationTarget { device: i64 },
+    ConfirmMigrateDevice { device: i64, target: i64 },
+    MigrateDevice { device: i64, target: i64 },
+}
+
+#[derive(Serialize, Deserialize, Debug, Clone, Copy, PartialEq)]
+pub enum AutomationCmd {
+    Enable,
+    Disable,
+    Trigger,
 }
 
 impl Payload {
+    /// Тот же экран на странице `page`.
+    pub fn paged(self, page: u8) -> Payload {
+        let base = match self {
+            Payload::Page { of, .. } => *of,
+            payload => payload,
+        };
+        match page {
+            0 => base,
+            page => Payload::Page { of: Box::new(base), page },
+        }
+    }
+
+    /// Экран без номера страницы.
+    pub fn base(&self) -> &Payload {
+        match self {
+            Payload::Page { of, .. } => of,
+            payload => payload,
+        }
+    }
+
     pub fn to_string_json(&self) -> String {
         serde_json::to_string(self).unwrap_or_default()
     }
@@ -189,29 +397,129 @@ pub async fn router(
 
     info!("ROUTER CALL: user_id={}, payload {}", user_id, payload.to_string());
 
+    let (payload, page) = match payload {
+        Payload::Page { of, page } => (*of, page),
+        payload => (payload, 0),
+    };
+
     let ctx = RenderContext {
         user_id,
         config: config.clone(),
         notifications,
-        is_admin
+        is_admin,
+        page,
     };
 
-    match payload {
+    let mut view = match payload {
         Payload::Home {} => {
             Ok(super::screens::home::render(ctx).await?)
         }
+        Payload::Undo => router_undo(ctx).await,
         Payload::Control (sub_payload) => {
             Ok(router_control(ctx, sub_payload).await?)
         }
         Payload::Settings(sub_payload) => {
             Ok(router_settings(ctx, sub_payload).await?)
         }
+        Payload::Presence(sub_payload) => {
+            Ok(super::screens::presence::render(ctx, sub_payload).await?)
+        }
+        Payload::Weather(sub_payload) => {
+            Ok(super::screens::weather::render(ctx, sub_payload).await?)
+        }
+        Payload::Calendar(CalendarPayload::Upcoming { week }) => {
+            Ok(super::screens::calendar::render(ctx, week).await?)
+        }
+        Payload::Todo(sub_payload) => {
+            Ok(super::screens::todo::render(ctx, sub_payload).await?)
+        }
+        Payload::Search(sub_payload) => router_search(ctx, sub_payload).await,
+        Payload::Admin(sub_payload) if is_admin => {
+            Ok(router_admin(ctx, sub_payload).await?)
+        }
         Payload::InDev {} => {
             Ok(super::screens::common::in_dev_menu(ctx, Payload::Home).await?)
         }
         _ => {
             Ok(super::screens::common::in_dev_menu(ctx, Payload::Home).await?)
         }
+    }?;
+
+    config.undo.attach(user_id, &mut view);
+    Ok(view)
+}
+
+async fn router_undo(ctx: RenderContext) -> anyhow::Result<View> {
+    let Some(entry) = ctx.config.undo.take(ctx.user_id) else {
+        let mut view = super::screens::home::render(ctx).await?;
+        view.alert = Some("Нечего отменять".into());
+        return Ok(view);
+    };
+
+    let alert = if !entry.is_fresh() {
+        "Время для отмены истекло"
+    } else if crate::core::undo::apply(&ctx.config, &entry).await.is_err() {
+        "Не удалось отменить действие"
+    } else {
+        "↩ Действие отменено"
+    };
+
+    let mut view = router_control(ctx, entry.return_to).await?;
+    view.alert = Some(alert.into());
+    Ok(view)
+}
+
+async fn router_search(ctx: RenderContext, payload: SearchPayload) -> anyhow::Result<View> {
+    use super::screens::search;
+
+    match payload {
+        SearchPayload::Prompt => Ok(search::render_prompt(ctx)),
+        SearchPayload::Results => search::render(ctx).await,
+        SearchPayload::Action { device } => {
+            let dev = crate::db::devices::get_device_by_id(device, &ctx.config.db).await?.context("Device not found")?;
+            let domain = dev.entity_id.split('.').next().unwrap_or("");
+            let Some(cmd) = crate::core::kinds::for_domain(domain).room_cmd() else {
+                return super::screens::control::device_control::render(ctx, dev.room_id, device, DeviceCmd::default()).await;
+            };
+
+            match devices::handle_device_interaction(&ctx.config, ctx.user_id, device, cmd.clone().into()).await? {
+                InteractionResult::Error { error } => {
+                    let mut view = search::render(ctx).await?;
+                    view.alert = Some(error);
+                    Ok(view)
+                }
+                InteractionResult::RequiresDetail | InteractionResult::RequiresInput(_) => {
+                    super::screens::control::device_control::render(ctx, dev.room_id, device, cmd).await
+                }
+                InteractionResult::Processed => search::render(ctx).await,
+            }
+        }
+    }
+}
+
+async fn router_admin(ctx: RenderContext, payload: AdminPayload) -> anyhow::Result<View> {
+    use super::screens::admin;
+
+    match payload {
+        AdminPayload::ListActions => Ok(admin::list_actions::render(ctx).await?),
+        AdminPayload::Automations => Ok(admin::automations::render_list(ctx).await?),
+        AdminPayload::Automation { key } => Ok(admin::automations::render_detail(ctx, key, None).await?),
+        AdminPayload::AutomationCmd { key, cmd } => Ok(admin::automations::render_detail(ctx, key, Some(cmd)).await?),
+        AdminPayload::Updates => Ok(admin::updates::render_list(ctx).await?),
+        AdminPayload::Update { key } => Ok(admin::updates::render_detail(ctx, key, false).await?),
+        AdminPayload::ConfirmInstall { key } => Ok(admin::updates::render_confirm(ctx, key).await?),
+        AdminPayload::Install { key } => Ok(admin::updates::render_detail(ctx, key, true).await?),
+        AdminPayload::HaNotifications => Ok(admin::ha_notifications::render(ctx, None).await?),
+        AdminPayload::DismissHaNotification { key } => Ok(admin::ha_notifications::render(ctx, Some(key)).await?),
+        AdminPayload::ArchivedDevices => admin::archived::render_list(ctx).await,
+        AdminPayload::ArchivedDevice { device } => admin::archived::render_detail(ctx, device).await,
+        AdminPayload::RestoreDevice { device } => admin::archived::restore(ctx, device).await,
+        AdminPayload::ConfirmPurgeDevice { device } => admin::archived::render_confirm_purge(ctx, device).await,
+        AdminPayload::PurgeDevice { device } => admin::archived::purge(ctx, device).await,
+        AdminPayload::PickMigrationTarget { device } => admin::archived::render_targets(ctx, device).await,
+        AdminPayload::ConfirmMigrateDevice { device, target } => admin::archived::render_confirm_migrate(ctx, device, target).await,
+        AdminPayload::MigrateDevice { device, target } => admin::archived::migrate(ctx, device, target).await,
+        _ => Ok(super::screens::common::in_dev_menu(ctx, Payload::Admin(AdminPayload::ListActions)).await?),
     }
 }
 
@@ -219,10 +527,68 @@ async fn router_control(ctx: RenderContext, payload: ControlPayload) -> anyhow::
     match payload {
         ControlPayload::ListRooms => Ok(super::screens::rooms::render(ctx, RoomViewMode::Control).await?),
         ControlPayload::RoomDetail {room} => Ok(room::render(ctx, room, RoomViewMode::Control).await?),
+        ControlPayload::DeviceControl {room, device} => {
+            Ok(super::screens::control::device_control::render(ctx, room, device, DeviceCmd::default()).await?)
+        }
+        ControlPayload::QuickAction {room, device, cmd: DeviceCmd::RecordClip } => {
+            let dev = crate::db::devices::get_device_by_id(device, &ctx.config.db).await?.context("Device not found")?;
+            let job = crate::video_engine::ClipJob {
+                user_id: ctx.user_id,
+                title: dev.alias.clone().unwrap_or_else(|| dev.entity_id.clone()),
+                entity_id: dev.entity_id,
+            };
+
+            match ctx.config.video.enqueue(job) {
+                Ok(()) => Ok(super::screens::control::device_control::render(ctx, room, device, DeviceCmd::RecordClip).await?),
+                Err(e) => Ok(View {
+                    alert: Some(e.to_string()),
+                    ..Default::default()
+                }),
+            }
+        }
+        ControlPayload::RoomBulk { room, action } => {
+            let report = crate::core::bulk::run_room(&ctx.config, ctx.user_id, room, action).await?;
+            let mut view = room::render(ctx, room, RoomViewMode::Control).await?;
+            view.text = format!("{}\n\n{}", view.text, report.summary());
+            Ok(view)
+        }
+        ControlPayload::LeaveHome { confirmed: false } => Ok(super::screens::home::render_leave_confirm(ctx)),
+        ControlPayload::LeaveHome { confirmed: true } => {
+            let report = crate::core::bulk::run_house(&ctx.config, ctx.user_id, BulkAction::AllOff).await?;
+            let mut view = super::screens::home::render(ctx).await?;
+            view.text = format!("{}\n\n🚪 Уходим из дома. {}", view.text, report.summary());
+            Ok(view)
+        }
+        ControlPayload::FavoriteAction { device } => {
+            let dev = crate::db::devices::get_device_by_id(device, &ctx.config.db).await?.context("Device not found")?;
+            let domain = dev.entity_id.split('.').next().unwrap_or("");
+            let Some(cmd) = crate::core::kinds::for_domain(domain).room_cmd() else {
+                return super::screens::control::device_control::render(ctx, dev.room_id, device, DeviceCmd::default()).await;
+            };
+
+            match devices::handle_device_interaction(&ctx.config, ctx.user_id, device, cmd.clone().into()).await? {
+                InteractionResult::Error { error } => {
+                    let mut view = super::screens::home::render(ctx).await?;
+                    view.alert = Some(error);
+                    Ok(view)
+                }
+                InteractionResult::RequiresDetail | InteractionResult::RequiresInput(_) => {
+                    super::screens::control::device_control::render(ctx, dev.room_id, device, cmd).await
+                }
+                InteractionResult::Processed => super::screens::home::render(ctx).await,
+            }
+        }
         ControlPayload::QuickAction {room, device, cmd } => {
+            // Неподдерживаемые типом команды (навигация по карточке, черновики) только открывают карточку
+            let dev = crate::db::devices::get_device_by_id(device, &ctx.config.db).await?.context("Device not found")?;
+            let domain = dev.entity_id.split('.').next().unwrap_or("");
+            if !crate::core::kinds::for_domain(domain).supports(&cmd) {
+                return super::screens::control::device_control::render(ctx, room, device, cmd).await;
+            }
+
             let action = devices::DeviceAction::from(cmd.clone());
 
-            let result = devices::handle_device_interaction(&ctx.config, device, action).await?;
+            let result = devices::handle_device_interaction(&ctx.config, ctx.user_id, device, action).await?;
 
             match result {
                 InteractionResult::Processed => {
@@ -233,7 +599,15 @@ async fn router_control(ctx: RenderContext, payload: ControlPayload) -> anyhow::
                 }
                 InteractionResult::RequiresInput(intent) => {
                     let state = State::from_intent(intent, device, room);
-                    Ok(super::screens::control::sensor_view::render_manual_input(room, device, state))
+                    match state {
+                        State::WaitingForNumber { .. } => {
+                            Ok(super::screens::control::number_view::render_manual_input(ctx, room, device, state).await?)
+                        }
+                        State::WaitingForText { .. } => {
+                            Ok(super::screens::control::text_view::render_manual_input(ctx, room, device, state).await?)
+                        }
+                        _ => Ok(super::screens::control::sensor_view::render_manual_input(room, device, state)),
+                    }
                 }
                 InteractionResult::Error { error: e } => {
                     Ok(View{
@@ -243,9 +617,6 @@ async fn router_control(ctx: RenderContext, payload: ControlPayload) -> anyhow::
                 }
             }
         }
-        _ => {
-            Ok(super::screens::common::in_dev_menu(ctx, Payload::Control(ControlPayload::ListRooms {})).await?)
-        }
     }
 }
 
@@ -262,10 +633,114 @@ async fn router_settings(ctx: RenderContext, payload: SettingsPayload) -> anyhow
         }
 
         SettingsPayload::ToggleHide { room, device } => {
+            let dev = db::devices::get_device_by_id(device, &ctx.config.db).await?.context("Device not found")?;
+            db::subscriptions::toggle_hidden_for(ctx.user_id, &dev.entity_id, &ctx.config.db).await?;
+            super::screens::settings::device_settings::render(ctx, room, device).await
+        }
+        SettingsPayload::ToggleHideGlobal { room, device } if ctx.is_admin => {
             let dev = db::devices::get_device_by_id(device, &ctx.config.db).await?.context("Device not found")?;
             db::subscriptions::toggle_hidden(&dev.entity_id, &ctx.config.db).await?;
             super::screens::settings::device_settings::render(ctx, room, device).await
         }
+
+        SettingsPayload::PickCamera { room, device } => {
+            super::screens::settings::camera_link::render(ctx, room, device).await
+        }
+
+        SettingsPayload::LinkCamera { room, device, camera } => {
+            let dev = db::devices::get_device_by_id(device, &ctx.config.db).await?.context("Device not found")?;
+            let camera_eid = match camera {
+                0 => None,
+                id => Some(db::devices::get_device_by_id(id, &ctx.config.db).await?.context("Camera not found")?.entity_id),
+            };
+            db::subscriptions::set_linked_camera(ctx.user_id as i64, &dev.entity_id, camera_eid.as_deref(), &ctx.config.db).await?;
+            super::screens::settings::device_settings::render(ctx, room, device).await
+        }
+
+        SettingsPayload::LocationTracker => super::screens::settings::location_tracker::render(ctx).await,
+        SettingsPayload::EditLocationTracker => Ok(super::screens::settings::location_tracker::render_input()),
+        SettingsPayload::ClearLocationTracker => {
+            db::location_trackers::clear_dev_id(ctx.user_id, &ctx.config.db).await?;
+            super::screens::settings::location_tracker::render(ctx).await
+        }
+        SettingsPayload::ToggleFavorite { room, device } => {
+            let dev = db::devices::get_device_by_id(device, &ctx.config.db).await?.context("Device not found")?;
+            ctx.config.toggle_header_pin(ctx.user_id, &dev.entity_id).await?;
+            super::screens::settings::device_settings::render(ctx, room, device).await
+        }
+        SettingsPayload::Favorites => super::screens::settings::favorites::render(ctx).await,
+        SettingsPayload::MoveFavorite { key, up } => {
+            if let Some(entity_id) = super::screens::settings::favorites::find_pinned(&ctx, key) {
+                ctx.config.move_header_pin(ctx.user_id, &entity_id, up).await?;
+            }
+            super::screens::settings::favorites::render(ctx).await
+        }
+        SettingsPayload::RemoveFavorite { key } => {
+            if let Some(entity_id) = super::screens::settings::favorites::find_pinned(&ctx, key) {
+                ctx.config.toggle_header_pin(ctx.user_id, &entity_id).await?;
+            }
+            super::screens::settings::favorites::render(ctx).await
+        }
+        SettingsPayload::EditName { room, device } if ctx.is_admin => {
+            let dev = db::devices::get_device_by_id(device, &ctx.config.db).await?.context("Device not found")?;
+            Ok(super::screens::settings::device_settings::render_name_input(room, &dev))
+        }
+        SettingsPayload::EditStateAlias { room, device } if ctx.is_admin => {
+            let dev = db::devices::get_device_by_id(device, &ctx.config.db).await?.context("Device not found")?;
+            let entity = ctx.config.ha_client.fetch_states_by_ids(std::slice::from_ref(&dev.entity_id)).await?
+                .into_iter().next().context("HA offline")?;
+            Ok(super::screens::settings::device_settings::render_state_alias_input(room, device, entity.state))
+        }
+        SettingsPayload::MoveDevice { room: room_id, device, up } if ctx.is_admin => {
+            db::devices::move_device(device, up, &ctx.config.db).await?;
+            room::render(ctx, room_id, RoomViewMode::Settings).await
+        }
+        SettingsPayload::PickDeviceGroup { room, device } if ctx.is_admin => {
+            super::screens::settings::device_group::render(ctx, room, device).await
+        }
+        SettingsPayload::SetDeviceGroup { room, device, group } if ctx.is_admin => {
+            let groups = db::devices::get_room_groups(room, &ctx.config.db).await?;
+            let group = groups.get(group as usize).map(String::as_str);
+            db::devices::set_device_group(device, group, &ctx.config.db).await?;
+            super::screens::settings::device_settings::render(ctx, room, device).await
+        }
+        SettingsPayload::NewDeviceGroup { room, device } if ctx.is_admin => {
+            Ok(super::screens::settings::device_group::render_input(room, device))
+        }
+        SettingsPayload::ToggleCompactSensors { room } if ctx.is_admin => {
+            db::rooms::toggle_compact_sensors(room, &ctx.config.db).await?;
+            super::screens::settings::room_settings::render(ctx, room).await
+        }
+        SettingsPayload::RoomOptions { room } if ctx.is_admin => {
+            super::screens::settings::room_settings::render(ctx, room).await
+        }
+        SettingsPayload::RenameRoom { room } if ctx.is_admin => {
+            Ok(super::screens::settings::room_settings::render_name_input(room))
+        }
+        SettingsPayload::ToggleRoomHide { room } if ctx.is_admin => {
+            let r = db::rooms::get_room_by_id(room, &ctx.config.db).await?.context("Room not found")?;
+            if r.hide {
+                db::rooms::show_room(&r.area, &ctx.config.db).await?;
+            } else {
+                db::rooms::hide_room(&r.area, &ctx.config.db).await?;
+            }
+            super::screens::settings::room_settings::render(ctx, room).await
+        }
+        SettingsPayload::PickRoomIcon { room } if ctx.is_admin => {
+            super::screens::settings::room_settings::render_icon_picker(ctx, room).await
+        }
+        SettingsPayload::SetRoomIcon { room, icon } if ctx.is_admin => {
+            let icon = super::screens::settings::room_settings::PRESET_ICONS.get(icon as usize).copied();
+            db::rooms::set_room_icon(room, icon, &ctx.config.db).await?;
+            super::screens::settings::room_settings::render(ctx, room).await
+        }
+        SettingsPayload::EnterRoomIcon { room } if ctx.is_admin => {
+            Ok(super::screens::settings::room_settings::render_icon_input(room))
+        }
+        SettingsPayload::MoveRoom { room, up } if ctx.is_admin => {
+            db::rooms::move_room(room, up, &ctx.config.db).await?;
+            super::screens::settings::room_settings::render(ctx, room).await
+        }
         _ => {
             Ok(super::screens::common::in_dev_menu(ctx, Payload::Settings(SettingsPayload::ListRooms {})).await?)
         }
@@ -302,6 +777,24 @@ mod tests {
         assert_eq!(restored, original, "Data corruption: restored payload differs from original");
     }
 
+    #[test]
+    fn test_paged_payload_size() {
+        let base = Payload::Control(ControlPayload::QuickAction {
+            room: 1_000_000,
+            device: 2_000_000,
+            cmd: DeviceCmd::ShowChart { h: 168, o: -168 },
+        });
+
+        let paged = base.clone().paged(255);
+        let encoded = paged.to_string();
+        assert!(encoded.len() <= 64, "🛑 Payload overflow: {} bytes used. Max is 64.", encoded.len());
+        assert_eq!(Payload::from_string(&encoded).unwrap(), paged);
+
+        // Повторная обертка заменяет страницу, нулевая снимает обертку
+        assert_eq!(paged.clone().paged(3), base.clone().paged(3));
+        assert_eq!(paged.paged(0), base);
+    }
+
     #[tokio::test]
     async fn test_sensor_render_preserves_payload_context() -> anyhow::Result<()> {
         let encoded_input = "AQMENAUYAA";
@@ -335,6 +828,12 @@ mod tests {
             name_aliases: DashMap::new(),
 
             state_aliases: DashMap::new(),
+
+            video: crate::video_engine::VideoProcessor::new().0,
+
+            confirmations: crate::core::confirmations::StateConfirmations::new(),
+            undo: crate::core::undo::UndoStore::default(),
+            search_queries: DashMap::new(),
         });
 
         let user_id = 219791289;
@@ -0,0 +1,245 @@
+use teloxide::types::{InlineKeyboardButton, InlineKeyboardMarkup};
+use anyhow::Result;
+
+use crate::bot::models::View;
+use crate::bot::router::{AdminPayload, Payload, RenderContext};
+use crate::core::presentation::StateFormatter;
+use crate::core::types::{ArchivedDevice, Device};
+use crate::db;
+
+fn button(label: impl Into<String>, payload: AdminPayload) -> InlineKeyboardButton {
+    InlineKeyboardButton::callback(label, Payload::Admin(payload).to_string())
+}
+
+fn display_name(dev: &Device) -> &str {
+    dev.alias.as_deref().unwrap_or(&dev.entity_id)
+}
+
+fn archived_when(archived: &ArchivedDevice) -> String {
+    archived.archived_at
+        .map(StateFormatter::format_last_update)
+        .unwrap_or_else(|| "давно".into())
+}
+
+/// Устройства, пропавшие из Home Assistant: свежие первыми.
+pub async fn render_list(ctx: RenderContext) -> Result<View> {
+    let archived = db::devices::get_archived_devices(&ctx.config.db).await?;
+
+    let rows: Vec<_> = archived.iter()
+        .map(|a| vec![button(
+            format!("🗄 {} • {}", display_name(&a.device), archived_when(a)),
+            AdminPayload::ArchivedDevice { device: a.device.id },
+        )])
+        .collect();
+    let (mut rows, current_payload) = crate::bot::screens::pagination::paginate(
+        rows, ctx.page, &Payload::Admin(AdminPayload::ArchivedDevices)
+    );
+    rows.push(vec![crate::bot::screens::common::back_button(Payload::Admin(AdminPayload::ListActions))]);
+
+    let text = match archived.len() {
+        0 => "🗄 Архив устройств\n\nВсе устройства на месте.".to_string(),
+        n => format!(
+            "🗄 Архив устройств\n\n\
+            Пропали из Home Assistant: {}. Их подписки и настройки сохранены, \
+            пока устройство не удалено или не перенесено на новую сущность.",
+            n
+        ),
+    };
+
+    Ok(View {
+        header: Some("🛠 Админка".into()),
+        notifications: ctx.notifications,
+        text,
+        kb: InlineKeyboardMarkup::new(rows),
+        payload: current_payload,
+        ..Default::default()
+    })
+}
+
+pub async fn render_detail(ctx: RenderContext, device_id: i64) -> Result<View> {
+    let Some(archived) = db::devices::get_archived_device(device_id, &ctx.config.db).await? else {
+        return back_to_list(ctx, "Устройство уже не в архиве").await;
+    };
+    let dev = &archived.device;
+
+    let room = match db::rooms::get_room_by_id(dev.room_id, &ctx.config.db).await? {
+        Some(room) => room.display_name(),
+        None => "—".to_string(),
+    };
+
+    let text = format!(
+        "🗄 {}\n\n\
+        Сущность: {}\n\
+        Комната: {}\n\
+        Пропало из HA: {}\n\n\
+        Если сущность переименовали в Home Assistant, перенесите настройки на новую: \
+        имя, подписки, закрепления и видимость сохранятся.",
+        display_name(dev),
+        dev.entity_id,
+        room,
+        archived_when(&archived),
+    );
+
+    let rows = vec![
+        vec![button("🔀 Перенести на новую сущность", AdminPayload::PickMigrationTarget { device: device_id })],
+        vec![
+            button("♻️ Восстановить", AdminPayload::RestoreDevice { device: device_id }),
+            button("🗑 Удалить навсегда", AdminPayload::ConfirmPurgeDevice { device: device_id }),
+        ],
+        vec![crate::bot::screens::common::back_button(Payload::Admin(AdminPayload::ArchivedDevices))],
+    ];
+
+    Ok(View {
+        header: Some("🛠 Админка".into()),
+        notifications: ctx.notifications,
+        text,
+        kb: InlineKeyboardMarkup::new(rows),
+        payload: Payload::Admin(AdminPayload::ArchivedDevice { device: device_id }),
+        ..Default::default()
+    })
+}
+
+pub async fn restore(ctx: RenderContext, device_id: i64) -> Result<View> {
+    let Some(archived) = db::devices::get_archived_device(device_id, &ctx.config.db).await? else {
+        return back_to_list(ctx, "Устройство уже не в архиве").await;
+    };
+
+    ctx.config.restore_device(&archived.device).await?;
+    back_to_list(ctx, "Устройство восстановлено. Если сущности нет в HA, синхронизация снова уберет его в архив").await
+}
+
+pub async fn render_confirm_purge(ctx: RenderContext, device_id: i64) -> Result<View> {
+    let Some(archived) = db::devices::get_archived_device(device_id, &ctx.config.db).await? else {
+        return back_to_list(ctx, "Устройство уже не в архиве").await;
+    };
+
+    let text = format!(
+        "Удалить {} ({}) навсегда?\n\n\
+        Подписки, закрепления, подписи состояний и история событий будут удалены без возможности отмены.",
+        display_name(&archived.device),
+        archived.device.entity_id,
+    );
+
+    let rows = vec![vec![
+        button("🗑 Удалить", AdminPayload::PurgeDevice { device: device_id }),
+        button("❌ Отмена", AdminPayload::ArchivedDevice { device: device_id }),
+    ]];
+
+    Ok(View {
+        header: Some("🛠 Админка".into()),
+        notifications: ctx.notifications,
+        text,
+        kb: InlineKeyboardMarkup::new(rows),
+        payload: Payload::Admin(AdminPayload::ConfirmPurgeDevice { device: device_id }),
+        ..Default::default()
+    })
+}
+
+pub async fn purge(ctx: RenderContext, device_id: i64) -> Result<View> {
+    let Some(archived) = db::devices::get_archived_device(device_id, &ctx.config.db).await? else {
+        return back_to_list(ctx, "Устройство уже не в архиве").await;
+    };
+
+    ctx.config.purge_device(&archived.device).await?;
+    back_to_list(ctx, "🗑 Устройство удалено").await
+}
+
+/// Активные устройства того же домена - кандидаты на роль переименованной сущности.
+/// Устройства той же комнаты первыми.
+pub async fn render_targets(ctx: RenderContext, device_id: i64) -> Result<View> {
+    let Some(archived) = db::devices::get_archived_device(device_id, &ctx.config.db).await? else {
+        return back_to_list(ctx, "Устройство уже не в архиве").await;
+    };
+    let old = &archived.device;
+
+    let mut targets = db::devices::get_devices_by_domain(&old.device_domain, &ctx.config.db).await?;
+    targets.sort_by(|a, b| {
+        (a.room_id != old.room_id).cmp(&(b.room_id != old.room_id))
+            .then_with(|| display_name(a).cmp(display_name(b)))
+    });
+
+    let rows: Vec<_> = targets.iter()
+        .map(|t| vec![button(
+            format!("{} • {}", display_name(t), t.entity_id),
+            AdminPayload::ConfirmMigrateDevice { device: device_id, target: t.id },
+        )])
+        .collect();
+    let (mut rows, current_payload) = crate::bot::screens::pagination::paginate(
+        rows, ctx.page, &Payload::Admin(AdminPayload::PickMigrationTarget { device: device_id })
+    );
+    rows.push(vec![crate::bot::screens::common::back_button(
+        Payload::Admin(AdminPayload::ArchivedDevice { device: device_id })
+    )]);
+
+    let text = match targets.is_empty() {
+        true => format!("🔀 Нет активных устройств типа {} для переноса.", old.device_domain),
+        false => format!(
+            "🔀 Перенос {} ({})\n\nВыберите новую сущность этого устройства:",
+            display_name(old),
+            old.entity_id
+        ),
+    };
+
+    Ok(View {
+        header: Some("🛠 Админка".into()),
+        notifications: ctx.notifications,
+        text,
+        kb: InlineKeyboardMarkup::new(rows),
+        payload: current_payload,
+        ..Default::default()
+    })
+}
+
+pub async fn render_confirm_migrate(ctx: RenderContext, device_id: i64, target_id: i64) -> Result<View> {
+    let Some((old, target)) = load_migration(&ctx, device_id, target_id).await? else {
+        return back_to_list(ctx, "Устройство не найдено, список обновлен").await;
+    };
+
+    let text = format!(
+        "Перенести настройки {} на {}?\n\n\
+        Перейдут имя, группа, подписки, закрепления, видимость, подписи состояний и история. \
+        Совпадающие настройки новой сущности будут заменены, архивная запись удалена.",
+        old.entity_id,
+        target.entity_id,
+    );
+
+    let rows = vec![vec![
+        button("✅ Перенести", AdminPayload::MigrateDevice { device: device_id, target: target_id }),
+        button("❌ Отмена", AdminPayload::PickMigrationTarget { device: device_id }),
+    ]];
+
+    Ok(View {
+        header: Some("🛠 Админка".into()),
+        notifications: ctx.notifications,
+        text,
+        kb: InlineKeyboardMarkup::new(rows),
+        payload: Payload::Admin(AdminPayload::ConfirmMigrateDevice { device: device_id, target: target_id }),
+        ..Default::default()
+    })
+}
+
+pub async fn migrate(ctx: RenderContext, device_id: i64, target_id: i64) -> Result<View> {
+    let Some((old, target)) = load_migration(&ctx, device_id, target_id).await? else {
+        return back_to_list(ctx, "Устройство не найдено, список обновлен").await;
+    };
+
+    ctx.config.migrate_device(&old, &target).await?;
+    back_to_list(ctx, &format!("🔀 Настройки перенесены на {}", target.entity_id)).await
+}
+
+/// Архивное устройство и активная цель переноса, если обе записи еще актуальны.
+async fn load_migration(ctx: &RenderContext, device_id: i64, target_id: i64) -> Result<Option<(Device, Device)>> {
+    let Some(archived) = db::devices::get_archived_device(device_id, &ctx.config.db).await? else {
+        return Ok(None);
+    };
+    let target = db::devices::get_device_by_id(target_id, &ctx.config.db).await?
+        .filter(|t| t.archived == 0);
+
+    Ok(target.map(|t| (archived.device, t)))
+}
+
+async fn back_to_list(ctx: RenderContext, alert: &str) -> Result<View> {
+    let mut view = render_list(ctx).await?;
+    view.alert = Some(alert.into());
+    Ok(view)
+}
@@ -0,0 +1,176 @@
+use teloxide::types::{InlineKeyboardButton, InlineKeyboardMarkup};
+use anyhow::Result;
+
+use crate::bot::models::View;
+use crate::bot::router::{AdminPayload, AutomationCmd, Payload, RenderContext};
+use crate::core::automation;
+use crate::core::presentation::StateFormatter;
+use crate::core::short_key;
+use crate::ha::models::Entity;
+
+async fn fetch_automations(ctx: &RenderContext) -> Result<Vec<Entity>> {
+    let mut automations = ctx.config.ha_client.fetch_states_by_domain("automation").await?;
+    automations.sort_by(|a, b| a.entity_id.cmp(&b.entity_id));
+    Ok(automations)
+}
+
+pub async fn render_list(ctx: RenderContext) -> Result<View> {
+    let automations = fetch_automations(&ctx).await?;
+
+    let mut rows = vec![];
+
+    for a in &automations {
+        let mark = if a.state == "on" { "🟢" } else { "⚪" };
+        rows.push(vec![InlineKeyboardButton::callback(
+            format!("{} {}", mark, automation_name(a)),
+            Payload::Admin(AdminPayload::Automation { key: short_key(&a.entity_id) }).to_string()
+        )]);
+    }
+
+    let (mut rows, current_payload) = crate::bot::screens::pagination::paginate(
+        rows, ctx.page, &Payload::Admin(AdminPayload::Automations)
+    );
+
+    rows.push(vec![crate::bot::screens::common::back_button(Payload::Admin(AdminPayload::ListActions))]);
+
+    let enabled = automations.iter().filter(|a| a.state == "on").count();
+    let text = if automations.is_empty() {
+        "🤖 Автоматизации\n\nВ Home Assistant нет автоматизаций.".to_string()
+    } else {
+        format!("🤖 Автоматизации\n\nВсего: {}, включено: {}.", automations.len(), enabled)
+    };
+
+    Ok(View {
+        header: Some("🛠 Админка".into()),
+        notifications: ctx.notifications,
+        text,
+        kb: InlineKeyboardMarkup::new(rows),
+        payload: current_payload,
+        ..Default::default()
+    })
+}
+
+pub async fn render_detail(ctx: RenderContext, key: u32, cmd: Option<AutomationCmd>) -> Result<View> {
+    let ha = &ctx.config.ha_client;
+    let mut automations = fetch_automations(&ctx).await?;
+
+    let Some(entity_id) = automations.iter()
+        .find(|a| short_key(&a.entity_id) == key)
+        .map(|a| a.entity_id.clone())
+    else {
+        let mut view = render_list(ctx).await?;
+        view.alert = Some("Автоматизация не найдена, список обновлен".into());
+        return Ok(view);
+    };
+
+    let mut alert = None;
+
+    if let Some(cmd) = cmd {
+        let (service, target) = match cmd {
+            AutomationCmd::Enable => ("turn_on", Some("on")),
+            AutomationCmd::Disable => ("turn_off", Some("off")),
+            AutomationCmd::Trigger => ("trigger", None),
+        };
+        let previous = automations.iter()
+            .find(|a| a.entity_id == entity_id)
+            .map(|a| a.state.clone());
+
+        // Подписка до вызова сервиса: быстрое подтверждение не должно потеряться
+        let rx = ctx.config.confirmations.subscribe();
+        match ha.call_service("automation", service, &entity_id).await {
+            Ok(()) => {
+                ctx.config.confirmations.wait(rx, ha, &entity_id, previous.as_deref(), target).await;
+                automations = fetch_automations(&ctx).await?;
+            }
+            Err(e) => {
+                log::error!("Automation {} {} failed: {}", entity_id, service, e);
+                alert = Some("Не удалось выполнить команду".to_string());
+            }
+        }
+    }
+
+    let Some(position) = automations.iter().position(|a| a.entity_id == entity_id) else {
+        return render_list(ctx).await;
+    };
+    let entity = &automations[position];
+    let pending = if ctx.config.confirmations.is_pending(&entity_id) { " ⏳" } else { "" };
+
+    let mut lines = vec![
+        format!("🤖 {}", automation_name(entity)),
+        format!("Состояние: {}{}", StateFormatter::translate_state(&entity.state), pending),
+        format!(
+            "Последний запуск: {}",
+            automation::last_triggered(entity).map(StateFormatter::format_last_update).unwrap_or_else(|| "никогда".into())
+        ),
+    ];
+
+    lines.push(String::new());
+    match automation::config_id(entity) {
+        Some(item_id) => match automation::fetch_last_trace(ha, item_id).await {
+            Ok(Some(trace)) => lines.extend(trace_lines(&trace)),
+            Ok(None) => lines.push("Трассировок нет.".into()),
+            Err(e) => {
+                log::warn!("trace/list failed for {}: {}", entity_id, e);
+                lines.push("Трассировка недоступна.".into());
+            }
+        },
+        None => lines.push("У автоматизации нет id в конфигурации — HA не хранит трассировки.".into()),
+    }
+
+    let cmd_payload = |cmd| Payload::Admin(AdminPayload::AutomationCmd { key, cmd }).to_string();
+    let toggle = if entity.state == "on" {
+        InlineKeyboardButton::callback("⏸ Выключить", cmd_payload(AutomationCmd::Disable))
+    } else {
+        InlineKeyboardButton::callback("▶️ Включить", cmd_payload(AutomationCmd::Enable))
+    };
+
+    let rows = vec![
+        vec![toggle, InlineKeyboardButton::callback("⚡ Запустить", cmd_payload(AutomationCmd::Trigger))],
+        vec![InlineKeyboardButton::callback(
+            "🔄 Обновить",
+            Payload::Admin(AdminPayload::Automation { key }).to_string()
+        )],
+        vec![crate::bot::screens::common::back_button(
+            Payload::Admin(AdminPayload::Automations)
+                .paged((position / crate::bot::screens::pagination::PAGE_SIZE) as u8)
+        )],
+    ];
+
+    Ok(View {
+        header: Some("🛠 Админка".into()),
+        notifications: ctx.notifications,
+        text: lines.join("\n"),
+        kb: InlineKeyboardMarkup::new(rows),
+        // Live-обновление не должно повторно запускать автоматизацию
+        payload: Payload::Admin(AdminPayload::Automation { key }),
+        alert,
+        ..Default::default()
+    })
+}
+
+fn trace_lines(trace: &automation::TraceSummary) -> Vec<String> {
+    let mut lines = vec![format!("🧾 Последняя трассировка: {}", StateFormatter::format_last_update(trace.start))];
+
+    if let Some(execution) = &trace.execution {
+        lines.push(format!("Результат: {}", automation::translate_execution(execution)));
+    }
+    if let Some(trigger) = &trace.trigger {
+        lines.push(format!("Триггер: {}", trigger));
+    }
+    if let Some(ms) = trace.duration_ms() {
+        lines.push(format!("Длительность: {:.1} с", ms as f64 / 1000.0));
+    }
+    if let Some(step) = &trace.last_step {
+        lines.push(format!("Последний шаг: {}", step));
+    }
+    if let Some(error) = &trace.error {
+        lines.push(format!("Ошибка: {}", error));
+    }
+    lines
+}
+
+fn automation_name(automation: &Entity) -> String {
+    automation.friendly_name.clone()
+        .filter(|n| !n.is_empty())
+        .unwrap_or_else(|| automation.entity_id.clone())
+}
@@ -0,0 +1,174 @@
+use teloxide::types::{InlineKeyboardButton, InlineKeyboardMarkup};
+use anyhow::Result;
+
+use crate::bot::models::View;
+use crate::bot::router::{AdminPayload, Payload, RenderContext};
+use crate::core::persistent_notifications;
+use crate::ha::{PersistentNotification, RepairIssue};
+use crate::core::presentation::StateFormatter;
+
+/// Общий бюджет текста экрана: View уходит подписью к фото (лимит 1024 символа),
+/// часть которой занимают шапка и закрепленные сущности.
+const TEXT_MAX_CHARS: usize = 750;
+/// Раскрывается только самое новое уведомление, остальные выводятся заголовками.
+const EXPANDED_MAX_CHARS: usize = 500;
+/// Длина строки-заголовка в списке: одно длинное название не должно съесть весь бюджет.
+const TITLE_MAX_CHARS: usize = 60;
+
+pub async fn render(ctx: RenderContext, dismiss: Option<u32>) -> Result<View> {
+    let ha = &ctx.config.ha_client;
+    let mut alert = None;
+
+    let mut notifications = persistent_notifications::fetch_active(ha).await?;
+    // Repairs - дополнение к экрану: без них список уведомлений все равно полезен
+    let repairs = persistent_notifications::fetch_repairs(ha).await.unwrap_or_else(|e| {
+        log::warn!("Failed to fetch HA repairs: {}", e);
+        Vec::new()
+    });
+
+    if let Some(key) = dismiss {
+        // Ищем по ключу: уведомление могло быть уже закрыто в HA
+        match notifications.iter().position(|n| crate::core::short_key(&n.notification_id) == key) {
+            Some(pos) => match persistent_notifications::dismiss(ha, &notifications[pos].notification_id).await {
+                Ok(()) => { notifications.remove(pos); }
+                Err(e) => {
+                    log::error!("Failed to dismiss HA notification: {}", e);
+                    alert = Some("Не удалось закрыть уведомление".to_string());
+                }
+            },
+            None => alert = Some("Уведомление уже закрыто".to_string()),
+        }
+    }
+
+    let rows: Vec<_> = notifications.iter().map(|n| {
+        let title = n.title.clone().filter(|t| !t.is_empty()).unwrap_or_else(|| n.notification_id.clone());
+        vec![InlineKeyboardButton::callback(
+            format!("✖️ {}", title),
+            Payload::Admin(AdminPayload::DismissHaNotification {
+                key: crate::core::short_key(&n.notification_id)
+            }).paged(ctx.page).to_string()
+        )]
+    }).collect();
+    let (mut rows, current_payload) = crate::bot::screens::pagination::paginate(
+        rows, ctx.page, &Payload::Admin(AdminPayload::HaNotifications)
+    );
+
+    rows.push(vec![InlineKeyboardButton::callback("🔄 Обновить", Payload::Admin(AdminPayload::HaNotifications).to_string())]);
+    rows.push(vec![crate::bot::screens::common::back_button(Payload::Admin(AdminPayload::ListActions))]);
+
+    Ok(View {
+        header: Some("🛠 Админка".into()),
+        notifications: ctx.notifications,
+        text: build_text(&notifications, &repairs),
+        kb: InlineKeyboardMarkup::new(rows),
+        // Live-обновление не должно повторно закрывать уведомления
+        payload: current_payload,
+        alert,
+        ..Default::default()
+    })
+}
+
+/// Текст экрана в пределах [`TEXT_MAX_CHARS`]: самое новое уведомление целиком,
+/// затем заголовки остальных и проблемы Repairs, пока хватает места.
+fn build_text(notifications: &[PersistentNotification], repairs: &[RepairIssue]) -> String {
+    let mut text = "🔔 Уведомления Home Assistant\n".to_string();
+
+    let Some((first, rest)) = notifications.split_first() else {
+        text.push_str("\nАктивных уведомлений нет.\n");
+        push_list(&mut text, "🛠 Проблемы (Repairs)", repairs.iter().map(repair_line));
+        return text;
+    };
+
+    let created = first.created_at.map(StateFormatter::format_last_update).unwrap_or_default();
+    let expanded = persistent_notifications::format_notification(first, EXPANDED_MAX_CHARS);
+    text.push_str(&format!("\n• {} {}\n", truncate(&expanded, EXPANDED_MAX_CHARS + TITLE_MAX_CHARS), created));
+
+    push_list(&mut text, "Еще уведомления", rest.iter().map(|n| {
+        let title = n.title.as_deref().filter(|t| !t.is_empty()).unwrap_or(&n.notification_id);
+        format!("• {}", truncate(title, TITLE_MAX_CHARS))
+    }));
+    push_list(&mut text, "🛠 Проблемы (Repairs)", repairs.iter().map(repair_line));
+
+    text
+}
+
+/// Добавляет раздел строками, пока не исчерпан бюджет; остаток - счетчиком.
+fn push_list(text: &mut String, title: &str, lines: impl ExactSizeIterator<Item = String>) {
+    let total = lines.len();
+    if total == 0 {
+        return;
+    }
+
+    let reserve = "…и еще 999".chars().count() + 1;
+    let mut section = format!("\n{}: {}", title, total);
+    let mut shown = 0;
+
+    for line in lines {
+        let used = text.chars().count() + section.chars().count();
+        if used + line.chars().count() + 1 + reserve > TEXT_MAX_CHARS {
+            break;
+        }
+        section.push('\n');
+        section.push_str(&line);
+        shown += 1;
+    }
+    if shown < total {
+        section.push_str(&format!("\n…и еще {}", total - shown));
+    }
+
+    // Даже заголовок раздела не помещается - раздел не выводим
+    if text.chars().count() + section.chars().count() <= TEXT_MAX_CHARS {
+        text.push_str(&section);
+        text.push('\n');
+    }
+}
+
+fn repair_line(issue: &RepairIssue) -> String {
+    let line = match issue.severity.as_str() {
+        "" => format!("{}: {}", issue.domain, issue.issue_id),
+        severity => format!("{}: {} ({})", issue.domain, issue.issue_id, severity),
+    };
+    format!("• {}", truncate(&line, TITLE_MAX_CHARS))
+}
+
+fn truncate(text: &str, max_chars: usize) -> String {
+    if text.chars().count() <= max_chars {
+        return text.to_string();
+    }
+    let mut short: String = text.chars().take(max_chars).collect();
+    short.push('…');
+    short
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+
+    #[test]
+    fn test_text_fits_photo_caption_with_long_notifications() {
+        let notification = |id: &str| PersistentNotification {
+            notification_id: id.into(),
+            title: Some("T".repeat(300)),
+            message: "x".repeat(1000),
+            created_at: None,
+        };
+        let notifications = vec![notification("a"), notification("b"), notification("c")];
+        let repairs: Vec<RepairIssue> = (0..20).map(|i| RepairIssue {
+            domain: "integration".repeat(5),
+            issue_id: format!("issue_{}", i),
+            severity: "warning".into(),
+            ignored: false,
+        }).collect();
+
+        let view = View {
+            header: Some("🛠 Админка".into()),
+            text: build_text(&notifications, &repairs),
+            ..Default::default()
+        };
+
+        assert!(view.text.chars().count() <= TEXT_MAX_CHARS);
+        assert!(view.get_text().chars().count() < 1024);
+        // Остальные уведомления не потерялись - хотя бы счетчиком
+        assert!(view.text.contains("Еще уведомления: 2"));
+    }
+}
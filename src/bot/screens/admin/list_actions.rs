@@ -1,43 +1,51 @@
 use crate::bot::models::View;
-use crate::bot::router::{AdminPayload, Payload, RenderContext, SettingsPayload};
+use crate::bot::router::{AdminPayload, Payload, RenderContext};
 
 use anyhow::Result;
 use teloxide::types::{InlineKeyboardButton, InlineKeyboardMarkup};
 
 
 pub async fn render(ctx: RenderContext) -> Result<View> {
-    let text = "Админ меню".to_string();
-    let kb = make_keyboard(ctx.is_admin);
+    let text = "🛠 Админка\n\nВыберите раздел:".to_string();
 
     Ok(View {
         notifications: ctx.notifications.clone(),
         text,
-        kb,
-        payload: Payload::Home {},
+        kb: make_keyboard(),
+        payload: Payload::Admin(AdminPayload::ListActions),
         ..Default::default()
     })
 }
 
-pub fn make_keyboard(root_admin:bool) -> InlineKeyboardMarkup {
-    let mut rows = vec![
+pub fn make_keyboard() -> InlineKeyboardMarkup {
+    let rows = vec![
         vec![InlineKeyboardButton::callback(
-            "Список пользователей",
-            Payload::Admin(AdminPayload::ListUsers).to_string()
+            "🤖 Автоматизации",
+            Payload::Admin(AdminPayload::Automations).to_string()
+        )],
+
+        vec![InlineKeyboardButton::callback(
+            "🔄 Обновления",
+            Payload::Admin(AdminPayload::Updates).to_string()
+        )],
+
+        vec![InlineKeyboardButton::callback(
+            "🔔 Уведомления HA",
+            Payload::Admin(AdminPayload::HaNotifications).to_string()
         )],
 
         vec![InlineKeyboardButton::callback(
-            "⚙️ Настройки",
-            Payload::Settings(SettingsPayload::ListRooms).to_string()
+            "🗄 Архив устройств",
+            Payload::Admin(AdminPayload::ArchivedDevices).to_string()
         )],
+
+        vec![InlineKeyboardButton::callback(
+            "👤 Список пользователей",
+            Payload::Admin(AdminPayload::ListUsers).to_string()
+        )],
+
+        vec![crate::bot::screens::common::back_button(Payload::Home)],
     ];
 
-    if root_admin {
-        rows.push(vec![
-            InlineKeyboardButton::callback(
-                "🛠 Админка",
-                Payload::Admin(AdminPayload::ListActions).to_string()
-            )
-        ]);
-    }
     InlineKeyboardMarkup::new(rows)
-}
\ No newline at end of file
+}
@@ -1 +1,5 @@
-mod list_actions;
\ No newline at end of file
+pub(crate) mod list_actions;
+pub(crate) mod automations;
+pub(crate) mod updates;
+pub(crate) mod ha_notifications;
+pub(crate) mod archived;
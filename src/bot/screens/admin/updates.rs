@@ -0,0 +1,152 @@
+use teloxide::types::{InlineKeyboardButton, InlineKeyboardMarkup};
+use anyhow::Result;
+
+use crate::bot::models::View;
+use crate::bot::router::{AdminPayload, Payload, RenderContext};
+use crate::core::updates;
+use crate::ha::models::Entity;
+
+/// Ограничение описания релиза, чтобы не превысить лимит подписи к фото.
+const SUMMARY_MAX_CHARS: usize = 600;
+
+pub async fn render_list(ctx: RenderContext) -> Result<View> {
+    let all = updates::fetch_updates(&ctx.config).await?;
+
+    let rows: Vec<_> = all.iter()
+        .filter(|u| updates::is_pending(u))
+        .map(|u| vec![InlineKeyboardButton::callback(
+            format!(
+                "{} {} → {}",
+                if updates::in_progress(u) { "⏳" } else { "⬆️" },
+                updates::title(u),
+                updates::latest_version(u).unwrap_or("?")
+            ),
+            Payload::Admin(AdminPayload::Update { key: updates::key(u) }).to_string()
+        )])
+        .collect();
+
+    let pending = rows.len();
+    let (mut rows, current_payload) = crate::bot::screens::pagination::paginate(
+        rows, ctx.page, &Payload::Admin(AdminPayload::Updates)
+    );
+    rows.push(vec![InlineKeyboardButton::callback("🔄 Обновить", Payload::Admin(AdminPayload::Updates).to_string())]);
+    rows.push(vec![crate::bot::screens::common::back_button(Payload::Admin(AdminPayload::ListActions))]);
+
+    let text = if pending == 0 {
+        format!("🔄 Обновления\n\nВсе актуально (проверено сущностей: {}).", all.len())
+    } else {
+        format!("🔄 Обновления\n\nДоступно: {}. Выберите обновление:", pending)
+    };
+
+    Ok(View {
+        header: Some("🛠 Админка".into()),
+        notifications: ctx.notifications,
+        text,
+        kb: InlineKeyboardMarkup::new(rows),
+        payload: current_payload,
+        ..Default::default()
+    })
+}
+
+pub async fn render_detail(ctx: RenderContext, key: u32, install: bool) -> Result<View> {
+    let all = updates::fetch_updates(&ctx.config).await?;
+
+    let Some(update) = updates::find_by_key(&all, key) else {
+        let mut view = render_list(ctx).await?;
+        view.alert = Some(match install {
+            true => "Обновление не найдено, установка отменена".into(),
+            false => "Обновление не найдено, список обновлен".into(),
+        });
+        return Ok(view);
+    };
+
+    let mut lines = describe(update);
+
+    if install && updates::is_pending(update) && !updates::in_progress(update) {
+        updates::spawn_install(ctx.config.clone(), update.entity_id.clone());
+        lines.push(String::new());
+        lines.push("⏳ Установка запущена. Статус обновится автоматически.".into());
+    } else if updates::in_progress(update) {
+        lines.push(String::new());
+        lines.push("⏳ Идет установка…".into());
+    }
+
+    let mut rows = vec![];
+    if updates::is_pending(update) && updates::supports_install(update) && !updates::in_progress(update) && !install {
+        rows.push(vec![InlineKeyboardButton::callback(
+            "⬇️ Установить",
+            Payload::Admin(AdminPayload::ConfirmInstall { key }).to_string()
+        )]);
+    }
+    rows.push(vec![InlineKeyboardButton::callback(
+        "🔄 Обновить",
+        Payload::Admin(AdminPayload::Update { key }).to_string()
+    )]);
+    rows.push(vec![crate::bot::screens::common::back_button(Payload::Admin(AdminPayload::Updates))]);
+
+    Ok(View {
+        header: Some("🛠 Админка".into()),
+        notifications: ctx.notifications,
+        text: lines.join("\n"),
+        kb: InlineKeyboardMarkup::new(rows),
+        // Live-обновление не должно повторно запускать установку
+        payload: Payload::Admin(AdminPayload::Update { key }),
+        ..Default::default()
+    })
+}
+
+pub async fn render_confirm(ctx: RenderContext, key: u32) -> Result<View> {
+    let all = updates::fetch_updates(&ctx.config).await?;
+
+    let Some(update) = updates::find_by_key(&all, key) else {
+        let mut view = render_list(ctx).await?;
+        view.alert = Some("Обновление не найдено, список обновлен".into());
+        return Ok(view);
+    };
+
+    let text = format!(
+        "Установить {} версии {}?\n\nУстройство или сервис может быть недоступно во время установки.",
+        updates::title(update),
+        updates::latest_version(update).unwrap_or("?")
+    );
+
+    let rows = vec![vec![
+        InlineKeyboardButton::callback("✅ Установить", Payload::Admin(AdminPayload::Install { key }).to_string()),
+        InlineKeyboardButton::callback("❌ Отмена", Payload::Admin(AdminPayload::Update { key }).to_string()),
+    ]];
+
+    Ok(View {
+        header: Some("🛠 Админка".into()),
+        notifications: ctx.notifications,
+        text,
+        kb: InlineKeyboardMarkup::new(rows),
+        payload: Payload::Admin(AdminPayload::ConfirmInstall { key }),
+        ..Default::default()
+    })
+}
+
+fn describe(update: &Entity) -> Vec<String> {
+    let mut lines = vec![
+        format!("🔄 {}", updates::title(update)),
+        format!("Установлено: {}", updates::installed_version(update)),
+        format!("Доступно: {}", updates::latest_version(update).unwrap_or("—")),
+    ];
+
+    if let Some(summary) = updates::release_summary(update) {
+        let mut short: String = summary.chars().take(SUMMARY_MAX_CHARS).collect();
+        if summary.chars().count() > SUMMARY_MAX_CHARS {
+            short.push('…');
+        }
+        lines.push(String::new());
+        lines.push(short);
+    }
+    if let Some(url) = updates::release_url(update) {
+        lines.push(String::new());
+        lines.push(format!("Подробнее: {}", url));
+    }
+    if !updates::supports_install(update) {
+        lines.push(String::new());
+        lines.push("Установка из бота не поддерживается этой интеграцией.".into());
+    }
+    lines
+}
@@ -0,0 +1,159 @@
+use teloxide::types::{InlineKeyboardButton, InlineKeyboardMarkup};
+use anyhow::Result;
+use chrono::{Datelike, Local, NaiveDate, Weekday};
+
+use crate::bot::models::View;
+use crate::bot::router::{CalendarPayload, Payload, RenderContext};
+use crate::core::calendar::{self, CalendarEvent};
+
+/// Бюджет текста экрана: View уходит подписью к фото (лимит 1024 символа),
+/// часть которой занимают шапка и закрепленные сущности.
+const TEXT_MAX_CHARS: usize = 750;
+/// Длина одной строки события: длинное название не должно съесть весь бюджет.
+const LINE_MAX_CHARS: usize = 120;
+
+pub async fn render(ctx: RenderContext, week: bool) -> Result<View> {
+    let ha = &ctx.config.ha_client;
+
+    let calendars = ha.fetch_states_by_domain("calendar").await?;
+    let names: std::collections::HashMap<_, _> = calendars.iter()
+        .map(|c| (c.entity_id.clone(), c.friendly_name.clone().filter(|n| !n.is_empty()).unwrap_or_else(|| c.entity_id.clone())))
+        .collect();
+    let ids: Vec<String> = calendars.into_iter().map(|c| c.entity_id).collect();
+
+    let mut alert = None;
+    let events = match calendar::fetch_upcoming_events(ha, &ids, week).await {
+        Ok(events) => events,
+        Err(e) => {
+            log::error!("Calendar events fetch failed: {}", e);
+            alert = Some("Не удалось получить события календаря".to_string());
+            Vec::new()
+        }
+    };
+
+    let title = if week { "📅 События на неделю" } else { "📅 События на сегодня" };
+    let mut lines = vec![title.to_string()];
+
+    if ids.is_empty() {
+        lines.push("\nВ Home Assistant нет сущностей calendar.".into());
+    } else if events.is_empty() && alert.is_none() {
+        lines.push("\nСобытий нет.".into());
+    }
+
+    let used = lines.iter().map(|l| l.chars().count() + 1).sum::<usize>();
+    let names = (ids.len() > 1).then_some(&names);
+    lines.extend(event_lines(&events, names, week, Local::now().date_naive(), TEXT_MAX_CHARS.saturating_sub(used)));
+
+    let rows = vec![
+        vec![
+            InlineKeyboardButton::callback(
+                if week { "Сегодня" } else { "✅ Сегодня" },
+                Payload::Calendar(CalendarPayload::Upcoming { week: false }).to_string()
+            ),
+            InlineKeyboardButton::callback(
+                if week { "✅ Неделя" } else { "Неделя" },
+                Payload::Calendar(CalendarPayload::Upcoming { week: true }).to_string()
+            ),
+        ],
+        vec![crate::bot::screens::common::back_button(Payload::Home)],
+    ];
+
+    Ok(View {
+        header: Some("📅 Календарь".into()),
+        notifications: ctx.notifications,
+        text: lines.join("\n"),
+        kb: InlineKeyboardMarkup::new(rows),
+        payload: Payload::Calendar(CalendarPayload::Upcoming { week }),
+        alert,
+        ..Default::default()
+    })
+}
+
+/// Строки событий (с заголовками дней в недельном режиме) в пределах `budget` символов.
+/// Что не поместилось, сводится в «…и еще N». `names` - подписи календарей, если их несколько.
+fn event_lines(
+    events: &[CalendarEvent],
+    names: Option<&std::collections::HashMap<String, String>>,
+    week: bool,
+    today: NaiveDate,
+    budget: usize,
+) -> Vec<String> {
+    let reserve = "…и еще 9999".chars().count() + 1;
+    let mut lines = Vec::new();
+    let mut used = 0;
+    let mut current_day = None;
+
+    for (shown, event) in events.iter().enumerate() {
+        let mut block = Vec::new();
+
+        let day = event.start.date();
+        if week && current_day != Some(day) {
+            let label = match (day - today).num_days() {
+                0 => "Сегодня".to_string(),
+                1 => "Завтра".to_string(),
+                _ => format!("{}, {}", day.format("%d.%m"), weekday_short(day.weekday())),
+            };
+            block.push(format!("\n{}", label));
+        }
+
+        let mut line = format!("• {} — {}", event.start.time_label(), event.summary);
+        if let Some(names) = names {
+            line.push_str(&format!(" ({})", names.get(&event.calendar).unwrap_or(&event.calendar)));
+        }
+        if let Some(location) = &event.location {
+            line.push_str(&format!(" 📍 {}", location));
+        }
+        if line.chars().count() > LINE_MAX_CHARS {
+            line = line.chars().take(LINE_MAX_CHARS).collect();
+            line.push('…');
+        }
+        block.push(line);
+
+        let size: usize = block.iter().map(|l| l.chars().count() + 1).sum();
+        if used + size + reserve > budget {
+            lines.push(format!("…и еще {}", events.len() - shown));
+            break;
+        }
+
+        used += size;
+        current_day = Some(day);
+        lines.extend(block);
+    }
+
+    lines
+}
+
+fn weekday_short(day: Weekday) -> &'static str {
+    match day {
+        Weekday::Mon => "пн",
+        Weekday::Tue => "вт",
+        Weekday::Wed => "ср",
+        Weekday::Thu => "чт",
+        Weekday::Fri => "пт",
+        Weekday::Sat => "сб",
+        Weekday::Sun => "вс",
+    }
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+    use crate::core::calendar::EventStart;
+
+    #[test]
+    fn test_event_lines_stay_within_budget() {
+        let today = NaiveDate::from_ymd_opt(2026, 10, 18).unwrap();
+        let events: Vec<CalendarEvent> = (0..40).map(|i| CalendarEvent {
+            calendar: "calendar.family".into(),
+            start: EventStart::AllDay(today + chrono::Duration::days(i % 7)),
+            summary: "Очень длинное название события ".repeat(10),
+            location: Some("Где-то далеко".into()),
+        }).collect();
+
+        let lines = event_lines(&events, None, true, today, TEXT_MAX_CHARS);
+        let text = lines.join("\n");
+
+        assert!(text.chars().count() <= TEXT_MAX_CHARS);
+        assert!(lines.last().is_some_and(|l| l.starts_with("…и еще")));
+    }
+}
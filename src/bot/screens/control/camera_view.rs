@@ -0,0 +1,63 @@
+use teloxide::types::{InlineKeyboardButton, InlineKeyboardMarkup};
+
+use crate::bot::models::View;
+use crate::bot::router::{ControlPayload, DeviceCmd, Payload, RenderContext};
+use crate::core::types::Device;
+use crate::ha::models::Entity;
+use crate::video_engine::CLIP_DURATION_S;
+
+pub async fn render(
+    ctx: RenderContext,
+    room_id: i64,
+    dev: Device,
+    entity: Entity,
+    cmd: DeviceCmd,
+) -> anyhow::Result<View> {
+    let device_name = dev.alias.as_deref().unwrap_or(&entity.entity_id);
+
+    // Камера может быть недоступна - показываем карточку без снимка
+    let (image, alert) = match ctx.config.ha_client.fetch_camera_snapshot(&entity.entity_id).await {
+        Ok(bytes) => (Some(bytes), None),
+        Err(e) => {
+            log::warn!("Camera snapshot failed for {}: {}", entity.entity_id, e);
+            (None, Some("Не удалось получить снимок с камеры".to_string()))
+        }
+    };
+
+    let mut text = format!(
+        "📷 {}\nСнимок: {}",
+        device_name,
+        chrono::Local::now().format("%H:%M:%S")
+    );
+    if cmd == DeviceCmd::RecordClip {
+        text.push_str(&format!("\n\n🎬 Клип ({}с) поставлен в очередь, видео придет отдельным сообщением.", CLIP_DURATION_S));
+    }
+
+    let current_payload = Payload::Control(ControlPayload::DeviceControl { room: room_id, device: dev.id });
+
+    let rows = vec![
+        vec![
+            InlineKeyboardButton::callback("🔄 Обновить", current_payload.to_string()),
+            InlineKeyboardButton::callback(
+                format!("🎬 Записать {}с", CLIP_DURATION_S),
+                Payload::Control(ControlPayload::QuickAction {
+                    room: room_id, device: dev.id, cmd: DeviceCmd::RecordClip
+                }).to_string()
+            ),
+        ],
+        vec![crate::bot::screens::common::back_button(
+            Payload::Control(ControlPayload::RoomDetail { room: room_id })
+        )],
+    ];
+
+    Ok(View {
+        header: Some("📷 Камера".into()),
+        notifications: ctx.notifications,
+        text,
+        kb: InlineKeyboardMarkup::new(rows),
+        payload: current_payload,
+        alert,
+        image,
+        ..Default::default()
+    })
+}
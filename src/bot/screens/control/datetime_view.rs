@@ -0,0 +1,119 @@
+use chrono::{Duration, Local, NaiveDateTime};
+use teloxide::types::{InlineKeyboardButton, InlineKeyboardMarkup};
+
+use crate::bot::models::View;
+use crate::bot::router::{ControlPayload, DeviceCmd, Payload, RenderContext};
+use crate::core::kinds::datetime::DateTimeSpec;
+use crate::core::types::Device;
+use crate::ha::models::Entity;
+
+pub async fn render(
+    ctx: RenderContext,
+    room_id: i64,
+    dev: Device,
+    entity: Entity,
+    cmd: DeviceCmd,
+) -> anyhow::Result<View> {
+    let spec = DateTimeSpec::from_entity(&entity);
+    let current = spec.parse_state(&entity.state);
+
+    // Черновик живет только в payload, в HA уходит по кнопке "Сохранить"
+    let draft = match cmd {
+        DeviceCmd::DraftDateTime(ts) | DeviceCmd::SetDateTime(ts) => from_ts(ts),
+        _ => None,
+    }
+        .or(current)
+        .unwrap_or_else(|| Local::now().naive_local());
+
+    let quick = |label: &str, delta: Duration| {
+        InlineKeyboardButton::callback(
+            label.to_string(),
+            Payload::Control(ControlPayload::QuickAction {
+                room: room_id,
+                device: dev.id,
+                cmd: DeviceCmd::DraftDateTime(to_ts(draft + delta)),
+            }).to_string()
+        )
+    };
+
+    let mut rows = vec![];
+
+    if spec.has_date {
+        rows.push(vec![
+            quick("−7д", Duration::days(-7)),
+            quick("−1д", Duration::days(-1)),
+            quick("+1д", Duration::days(1)),
+            quick("+7д", Duration::days(7)),
+        ]);
+    }
+
+    if spec.has_time {
+        rows.push(vec![
+            quick("−1ч", Duration::hours(-1)),
+            quick("−15м", Duration::minutes(-15)),
+            quick("+15м", Duration::minutes(15)),
+            quick("+1ч", Duration::hours(1)),
+        ]);
+        rows.push(vec![
+            quick("−5м", Duration::minutes(-5)),
+            quick("−1м", Duration::minutes(-1)),
+            quick("+1м", Duration::minutes(1)),
+            quick("+5м", Duration::minutes(5)),
+        ]);
+    }
+
+    let now = Local::now().naive_local();
+    rows.push(vec![
+        InlineKeyboardButton::callback(
+            "🕒 Сейчас",
+            Payload::Control(ControlPayload::QuickAction {
+                room: room_id, device: dev.id, cmd: DeviceCmd::DraftDateTime(to_ts(now))
+            }).to_string()
+        ),
+        InlineKeyboardButton::callback(
+            "✅ Сохранить",
+            Payload::Control(ControlPayload::QuickAction {
+                room: room_id, device: dev.id, cmd: DeviceCmd::SetDateTime(to_ts(draft))
+            }).to_string()
+        ),
+    ]);
+
+    rows.push(vec![crate::bot::screens::common::back_button(
+        Payload::Control(ControlPayload::RoomDetail { room: room_id })
+    )]);
+
+    let device_name = dev.alias.as_deref().unwrap_or(&entity.entity_id);
+    let current_text = current.map(|v| spec.format(v)).unwrap_or_else(|| "—".into());
+
+    let text = format!(
+        "📅 {}\nСохранено: {}\nНовое значение: {}\n\nНастройте значение и нажмите «Сохранить».",
+        device_name,
+        current_text,
+        spec.format(draft)
+    );
+
+    // После сохранения не повторяем запись при live-обновлении
+    let payload = match cmd {
+        DeviceCmd::DraftDateTime(_) => Payload::Control(ControlPayload::QuickAction {
+            room: room_id, device: dev.id, cmd: DeviceCmd::DraftDateTime(to_ts(draft))
+        }),
+        _ => Payload::Control(ControlPayload::DeviceControl { room: room_id, device: dev.id }),
+    };
+
+    Ok(View {
+        header: Some("📅 Дата и время".into()),
+        notifications: ctx.notifications,
+        text,
+        kb: InlineKeyboardMarkup::new(rows),
+        payload,
+        ..Default::default()
+    })
+}
+
+fn to_ts(value: NaiveDateTime) -> i64 {
+    value.and_utc().timestamp()
+}
+
+fn from_ts(ts: i64) -> Option<NaiveDateTime> {
+    chrono::DateTime::from_timestamp(ts, 0).map(|dt| dt.naive_utc())
+}
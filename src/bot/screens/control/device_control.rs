@@ -1,8 +1,7 @@
 use crate::bot::models::View;
-use crate::bot::router::{DeviceCmd, Payload, RenderContext};
+use crate::bot::router::{DeviceCmd, RenderContext};
 use anyhow::Context;
-
-use crate::core::devices::SmartDevice;
+use crate::core::HeaderItem;
 
 pub async fn render(
     ctx: RenderContext,
@@ -18,17 +17,17 @@ pub async fn render(
     let ha_ent = ctx.config.ha_client.fetch_states_by_ids(&[dev_db.entity_id.clone()]).await?
         .into_iter().next().context("HA state missing")?;
 
-    let smart_obj = SmartDevice::new(ha_ent);
-
-    // 2. Диспетчеризация отрисовки
-    match smart_obj {
-        SmartDevice::Sensor(e) | SmartDevice::BinarySensor(e) => {
-            super::sensor_view::render(ctx, room_id, dev_db, e, cmd).await
-        }
-        // SmartDevice::Light(e) => {
-        //     super::device_screens::light::render(ctx, room_id, dev_db, e).await
-        // }
-        // ... другие типы
-        _ => crate::bot::screens::common::in_dev_menu(ctx, Payload::Control(crate::bot::router::ControlPayload::RoomDetail {room: room_id})).await
+    let mut ctx = ctx;
+    if ctx.config.confirmations.is_pending(&dev_db.entity_id) {
+        ctx.notifications.insert(0, HeaderItem {
+            icon: "⏳".into(),
+            label: "Ожидание".into(),
+            value: "команда отправлена, HA еще не подтвердил".into(),
+            last_update: chrono::Utc::now(),
+        });
     }
+
+    // 2. Диспетчеризация отрисовки по типу устройства
+    let kind = crate::core::kinds::for_entity(&ha_ent);
+    kind.render_detail(ctx, room_id, dev_db, ha_ent, cmd).await
 }
@@ -0,0 +1,56 @@
+use teloxide::types::{InlineKeyboardButton, InlineKeyboardMarkup};
+
+use crate::bot::models::View;
+use crate::bot::router::{ControlPayload, Payload, RenderContext};
+use crate::core::kinds::event::event_type;
+use crate::core::presentation::StateFormatter;
+use crate::core::types::Device;
+use crate::ha::models::Entity;
+
+pub async fn render(
+    ctx: RenderContext,
+    room_id: i64,
+    dev: Device,
+    entity: Entity,
+) -> anyhow::Result<View> {
+    let device_name = dev.alias.as_deref().unwrap_or(&entity.entity_id);
+    let class = entity.device_class.as_deref().unwrap_or("");
+    let icon = StateFormatter::get_icon("event", class, &entity.state);
+
+    // state события - время последнего срабатывания
+    let last_time = chrono::DateTime::parse_from_rfc3339(&entity.state)
+        .map(|dt| StateFormatter::format_last_update(dt.with_timezone(&chrono::Utc)))
+        .unwrap_or_else(|_| StateFormatter::translate_state(&entity.state).to_string());
+
+    let last_event = event_type(&entity)
+        .map(|t| format!("{} ({})", StateFormatter::translate_event_type(t), last_time))
+        .unwrap_or_else(|| "событий еще не было".into());
+
+    let known_types: Vec<&str> = entity.attributes.get("event_types")
+        .and_then(|v| v.as_array())
+        .map(|arr| arr.iter().filter_map(|t| t.as_str()).map(StateFormatter::translate_event_type).collect())
+        .unwrap_or_default();
+
+    let mut text = format!("{} {}\nПоследнее событие: {}", icon, device_name, last_event);
+    if !known_types.is_empty() {
+        text.push_str(&format!("\nВозможные события: {}", known_types.join(", ")));
+    }
+
+    let current_payload = Payload::Control(ControlPayload::DeviceControl { room: room_id, device: dev.id });
+
+    let rows = vec![
+        vec![InlineKeyboardButton::callback("🔄 Обновить", current_payload.to_string())],
+        vec![crate::bot::screens::common::back_button(
+            Payload::Control(ControlPayload::RoomDetail { room: room_id })
+        )],
+    ];
+
+    Ok(View {
+        header: Some("🎛 Событие".into()),
+        notifications: ctx.notifications,
+        text,
+        kb: InlineKeyboardMarkup::new(rows),
+        payload: current_payload,
+        ..Default::default()
+    })
+}
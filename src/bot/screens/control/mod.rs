@@ -1,4 +1,9 @@
 pub(crate) mod device_control;
 pub(crate) mod sensor_view;
-mod number_view;
-mod climate_view;
\ No newline at end of file
+pub(crate) mod number_view;
+pub(crate) mod select_view;
+pub(crate) mod text_view;
+pub(crate) mod datetime_view;
+pub(crate) mod camera_view;
+mod climate_view;
+pub(crate) mod event_view;
@@ -0,0 +1,118 @@
+use anyhow::Context;
+use teloxide::types::{InlineKeyboardButton, InlineKeyboardMarkup};
+
+use crate::bot::models::View;
+use crate::bot::router::{ControlPayload, DeviceCmd, Payload, RenderContext};
+use crate::bot::State;
+use crate::core::kinds::number::NumberRange;
+use crate::core::types::Device;
+use crate::ha::models::Entity;
+
+// Пресеты быстрой установки (в процентах от диапазона)
+const QUICK_PERCENTS: [u8; 5] = [0, 25, 50, 75, 100];
+
+pub async fn render(
+    ctx: RenderContext,
+    room_id: i64,
+    dev: Device,
+    entity: Entity,
+) -> anyhow::Result<View> {
+    let range = NumberRange::from_entity(&entity);
+    let unit = entity.attributes.get("unit_of_measurement").and_then(|v| v.as_str()).unwrap_or("");
+    let current = entity.state.parse::<f64>().ok();
+
+    let set_value = |value: f64| {
+        Payload::Control(ControlPayload::QuickAction {
+            room: room_id,
+            device: dev.id,
+            cmd: DeviceCmd::SetValue(range.snap(value)),
+        }).to_string()
+    };
+
+    let mut rows = vec![];
+
+    // 1. Грубая и точная подстройка (только если текущее значение известно)
+    if let Some(value) = current {
+        let coarse = range.coarse_step();
+        let step = range.step;
+
+        rows.push(vec![
+            InlineKeyboardButton::callback(format!("⏬ {}", range.format(coarse)), set_value(value - coarse)),
+            InlineKeyboardButton::callback(format!("➖ {}", range.format(step)), set_value(value - step)),
+            InlineKeyboardButton::callback(format!("➕ {}", range.format(step)), set_value(value + step)),
+            InlineKeyboardButton::callback(format!("⏫ {}", range.format(coarse)), set_value(value + coarse)),
+        ]);
+    }
+
+    // 2. Быстрая установка по процентам диапазона
+    rows.push(QUICK_PERCENTS.iter().map(|p| {
+        InlineKeyboardButton::callback(format!("{}%", p), set_value(range.at_percent(*p)))
+    }).collect());
+
+    // 3. Ручной ввод и навигация
+    rows.push(vec![InlineKeyboardButton::callback(
+        "⌨️ Ввести значение",
+        Payload::Control(ControlPayload::QuickAction {
+            room: room_id, device: dev.id, cmd: DeviceCmd::EnterManualInput
+        }).to_string()
+    )]);
+
+    rows.push(vec![crate::bot::screens::common::back_button(
+        Payload::Control(ControlPayload::RoomDetail { room: room_id })
+    )]);
+
+    let device_name = dev.alias.as_deref().unwrap_or(&entity.entity_id);
+    let value_text = match current {
+        Some(v) => format!("{} {}", range.format(v), unit),
+        None => crate::core::presentation::StateFormatter::translate_state(&entity.state).to_string(),
+    };
+
+    let text = format!(
+        "🎚 {}\nТекущее значение: {}\nДиапазон: {} … {} (шаг {})",
+        device_name,
+        value_text.trim_end(),
+        range.format(range.min),
+        range.format(range.max),
+        range.format(range.step)
+    );
+
+    Ok(View {
+        header: Some("🎚 Значение".into()),
+        notifications: ctx.notifications,
+        text,
+        kb: InlineKeyboardMarkup::new(rows),
+        payload: Payload::Control(ControlPayload::DeviceControl { room: room_id, device: dev.id }),
+        ..Default::default()
+    })
+}
+
+pub async fn render_manual_input(
+    ctx: RenderContext,
+    room_id: i64,
+    device_id: i64,
+    state: State,
+) -> anyhow::Result<View> {
+    let dev = crate::db::devices::get_device_by_id(device_id, &ctx.config.db).await?
+        .context("Device not found")?;
+    let entity = ctx.config.ha_client.fetch_states_by_ids(std::slice::from_ref(&dev.entity_id)).await?
+        .into_iter().next().context("HA state missing")?;
+
+    let range = NumberRange::from_entity(&entity);
+    let cancel_payload = Payload::Control(ControlPayload::DeviceControl { room: room_id, device: device_id });
+
+    Ok(View {
+        header: Some("⌨️ Ввод данных".into()),
+        text: format!(
+            "Введите новое значение для «{}».\n\nДопустимо: от {} до {} (шаг {}).",
+            dev.alias.as_deref().unwrap_or(&dev.entity_id),
+            range.format(range.min),
+            range.format(range.max),
+            range.format(range.step)
+        ),
+        kb: InlineKeyboardMarkup::new(vec![vec![
+            InlineKeyboardButton::callback("❌ Отмена", cancel_payload.to_string())
+        ]]),
+        next_state: Some(state),
+        ..View::default()
+    })
+}
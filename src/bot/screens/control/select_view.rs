@@ -0,0 +1,83 @@
+use teloxide::types::{InlineKeyboardButton, InlineKeyboardMarkup};
+
+use crate::bot::models::View;
+use crate::bot::router::{ControlPayload, DeviceCmd, Payload, RenderContext};
+use crate::core::kinds::select::select_options;
+use crate::core::types::Device;
+use crate::ha::models::Entity;
+
+const OPTIONS_PER_PAGE: usize = 8;
+
+pub async fn render(
+    ctx: RenderContext,
+    room_id: i64,
+    dev: Device,
+    entity: Entity,
+    cmd: DeviceCmd,
+) -> anyhow::Result<View> {
+    let options = select_options(&entity);
+    let pages = options.len().div_ceil(OPTIONS_PER_PAGE).max(1);
+
+    // Страница: явная из навигации, либо та, где лежит выбранная/текущая опция
+    let page = match cmd {
+        DeviceCmd::Page(p) => p as usize,
+        DeviceCmd::SelectOption(i) => i as usize / OPTIONS_PER_PAGE,
+        _ => options.iter().position(|o| *o == entity.state).unwrap_or(0) / OPTIONS_PER_PAGE,
+    }.min(pages - 1);
+
+    let mut rows = vec![];
+
+    for (idx, option) in options.iter().enumerate().skip(page * OPTIONS_PER_PAGE).take(OPTIONS_PER_PAGE) {
+        let mark = if *option == entity.state { "🔘" } else { "⚪" };
+        rows.push(vec![InlineKeyboardButton::callback(
+            format!("{} {}", mark, option),
+            Payload::Control(ControlPayload::QuickAction {
+                room: room_id,
+                device: dev.id,
+                cmd: DeviceCmd::SelectOption(idx as u16),
+            }).to_string()
+        )]);
+    }
+
+    if pages > 1 {
+        let page_payload = |p: usize| Payload::Control(ControlPayload::QuickAction {
+            room: room_id,
+            device: dev.id,
+            cmd: DeviceCmd::Page(p as u8),
+        }).to_string();
+
+        let mut nav_row = vec![];
+        if page > 0 {
+            nav_row.push(InlineKeyboardButton::callback("‹", page_payload(page - 1)));
+        }
+        nav_row.push(InlineKeyboardButton::callback(format!("{}/{}", page + 1, pages), page_payload(page)));
+        if page + 1 < pages {
+            nav_row.push(InlineKeyboardButton::callback("›", page_payload(page + 1)));
+        }
+        rows.push(nav_row);
+    }
+
+    rows.push(vec![crate::bot::screens::common::back_button(
+        Payload::Control(ControlPayload::RoomDetail { room: room_id })
+    )]);
+
+    let device_name = dev.alias.as_deref().unwrap_or(&entity.entity_id);
+    let text = format!(
+        "📋 {}\nТекущий выбор: {}\n\nВыберите вариант:",
+        device_name,
+        crate::core::presentation::StateFormatter::translate_state(&entity.state)
+    );
+
+    Ok(View {
+        header: Some("📋 Выбор".into()),
+        notifications: ctx.notifications,
+        text,
+        kb: InlineKeyboardMarkup::new(rows),
+        payload: Payload::Control(ControlPayload::QuickAction {
+            room: room_id,
+            device: dev.id,
+            cmd: DeviceCmd::Page(page as u8),
+        }),
+        ..Default::default()
+    })
+}
@@ -5,7 +5,7 @@ use teloxide::types::{InlineKeyboardButton, InlineKeyboardMarkup};
 use crate::bot::models::View;
 use crate::bot::router::{ControlPayload, DeviceCmd, Payload, RenderContext};
 use crate::bot::State;
-use crate::core::devices::{ChartParams, SmartDevice};
+use crate::core::devices::ChartParams;
 use crate::core::types::Device;
 use crate::core::HeaderItem;
 
@@ -49,11 +49,8 @@ pub async fn render(
     });
 
     // 4. Отрисовка графика
-    let style = match SmartDevice::new(entity.clone()) {
-        SmartDevice::Sensor(_) => crate::charts::ChartStyle::Numeric,
-        SmartDevice::BinarySensor(_) => crate::charts::ChartStyle::Binary,
-        _ => return Err(anyhow::anyhow!("Тип устройства не поддерживает графики")),
-    };
+    let style = crate::core::kinds::for_entity(&entity).chart_style()
+        .context("Тип устройства не поддерживает графики")?;
 
     let device_name = dev.alias.as_deref().unwrap_or(&entity.entity_id);
     let image = crate::charts::draw_ha_chart(
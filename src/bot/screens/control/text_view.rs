@@ -0,0 +1,74 @@
+use anyhow::Context;
+use teloxide::types::{InlineKeyboardButton, InlineKeyboardMarkup};
+
+use crate::bot::models::View;
+use crate::bot::router::{ControlPayload, DeviceCmd, Payload, RenderContext};
+use crate::bot::State;
+use crate::core::kinds::text::TextLimits;
+use crate::core::types::Device;
+use crate::ha::models::Entity;
+
+pub async fn render(
+    ctx: RenderContext,
+    room_id: i64,
+    dev: Device,
+    entity: Entity,
+) -> anyhow::Result<View> {
+    let rows = vec![
+        vec![InlineKeyboardButton::callback(
+            "✏️ Изменить текст",
+            Payload::Control(ControlPayload::QuickAction {
+                room: room_id, device: dev.id, cmd: DeviceCmd::EnterManualInput
+            }).to_string()
+        )],
+        vec![crate::bot::screens::common::back_button(
+            Payload::Control(ControlPayload::RoomDetail { room: room_id })
+        )],
+    ];
+
+    let device_name = dev.alias.as_deref().unwrap_or(&entity.entity_id);
+    let value = if entity.state.is_empty() { "—" } else { entity.state.as_str() };
+
+    Ok(View {
+        header: Some("📝 Текст".into()),
+        notifications: ctx.notifications,
+        text: format!("📝 {}\nТекущее значение: {}", device_name, value),
+        kb: InlineKeyboardMarkup::new(rows),
+        payload: Payload::Control(ControlPayload::DeviceControl { room: room_id, device: dev.id }),
+        ..Default::default()
+    })
+}
+
+pub async fn render_manual_input(
+    ctx: RenderContext,
+    room_id: i64,
+    device_id: i64,
+    state: State,
+) -> anyhow::Result<View> {
+    let dev = crate::db::devices::get_device_by_id(device_id, &ctx.config.db).await?
+        .context("Device not found")?;
+    let entity = ctx.config.ha_client.fetch_states_by_ids(std::slice::from_ref(&dev.entity_id)).await?
+        .into_iter().next().context("HA state missing")?;
+
+    let limits = TextLimits::from_entity(&entity);
+    let pattern_hint = limits.pattern.as_deref()
+        .map(|p| format!("\nШаблон: {}", p))
+        .unwrap_or_default();
+    let cancel_payload = Payload::Control(ControlPayload::DeviceControl { room: room_id, device: device_id });
+
+    Ok(View {
+        header: Some("⌨️ Ввод данных".into()),
+        text: format!(
+            "Введите новый текст для «{}».\n\nДлина: от {} до {} символов.{}",
+            dev.alias.as_deref().unwrap_or(&dev.entity_id),
+            limits.min,
+            limits.max,
+            pattern_hint
+        ),
+        kb: InlineKeyboardMarkup::new(vec![vec![
+            InlineKeyboardButton::callback("❌ Отмена", cancel_payload.to_string())
+        ]]),
+        next_state: Some(state),
+        ..View::default()
+    })
+}
@@ -1,13 +1,15 @@
 use crate::bot::models::{View};
-use crate::bot::router::{AdminPayload, ControlPayload, Payload, RenderContext, SettingsPayload};
+use crate::bot::router::{AdminPayload, ControlPayload, Payload, PresencePayload, RenderContext, SettingsPayload, SearchPayload, TodoPayload, WeatherPayload, CalendarPayload};
 
 use anyhow::Result;
+use crate::core::kinds;
 use teloxide::types::{InlineKeyboardButton, InlineKeyboardMarkup};
 
 
 pub async fn render(ctx: RenderContext) -> Result<View> {
     let text = "Главное меню".to_string();
-    let kb = make_keyboard(ctx.is_admin);
+    let mut kb = make_keyboard(ctx.is_admin);
+    kb.inline_keyboard.splice(0..0, favorite_rows(&ctx).await);
 
     Ok(View {
         notifications: ctx.notifications.clone(),
@@ -18,11 +20,98 @@ pub async fn render(ctx: RenderContext) -> Result<View> {
     })
 }
 
+/// Кнопки избранных устройств: быстрое действие или карточка, как в списке комнаты.
+/// Закрепленные сущности без устройства в комнатах (например, погода) показываются только в шапке.
+async fn favorite_rows(ctx: &RenderContext) -> Vec<Vec<InlineKeyboardButton>> {
+    let pinned = ctx.config.get_pinned(ctx.user_id);
+    if pinned.is_empty() {
+        return Vec::new();
+    }
+
+    let entities = match ctx.config.ha_client.fetch_states_by_ids(&pinned).await {
+        Ok(entities) => entities,
+        Err(e) => {
+            log::error!("Не удалось получить избранные устройства: {}", e);
+            return Vec::new();
+        }
+    };
+
+    let mut rows = Vec::new();
+    for entity in &entities {
+        let Ok(Some(dev)) = crate::db::devices::get_device_by_entity(&entity.entity_id, &ctx.config.db).await else {
+            continue;
+        };
+
+        let alias = dev.alias.as_deref().unwrap_or(&dev.entity_id);
+        let payload = match kinds::for_entity(entity).room_cmd() {
+            Some(_) => ControlPayload::FavoriteAction { device: dev.id },
+            None => ControlPayload::DeviceControl { room: dev.room_id, device: dev.id },
+        };
+
+        rows.push(vec![InlineKeyboardButton::callback(
+            kinds::button_label(entity, alias, ctx.config.state_alias(&entity.entity_id, &entity.state).as_deref(), true),
+            Payload::Control(payload).to_string()
+        )]);
+    }
+    rows
+}
+
+/// Подтверждение «Ухожу из дома»: действие затрагивает все комнаты.
+pub fn render_leave_confirm(ctx: RenderContext) -> View {
+    let kb = InlineKeyboardMarkup::new(vec![
+        vec![InlineKeyboardButton::callback(
+            "✅ Да, выключить все",
+            Payload::Control(ControlPayload::LeaveHome { confirmed: true }).to_string()
+        )],
+        vec![crate::bot::screens::common::back_button(Payload::Home)],
+    ]);
+
+    View {
+        notifications: ctx.notifications,
+        text: "🚪 Ухожу из дома\n\nВыключить свет и все переключатели во всех комнатах?".to_string(),
+        kb,
+        payload: Payload::Control(ControlPayload::LeaveHome { confirmed: false }),
+        ..Default::default()
+    }
+}
+
 pub fn make_keyboard(root_admin:bool) -> InlineKeyboardMarkup {
     let mut rows = vec![
+        vec![
+            InlineKeyboardButton::callback(
+                "🏠 Управление",
+                Payload::Control(ControlPayload::ListRooms).to_string()
+            ),
+            InlineKeyboardButton::callback(
+                "🔍 Поиск",
+                Payload::Search(SearchPayload::Prompt).to_string()
+            ),
+        ],
+
+        vec![InlineKeyboardButton::callback(
+            "👥 Кто дома",
+            Payload::Presence(PresencePayload::List).to_string()
+        )],
+
+        vec![InlineKeyboardButton::callback(
+            "🌤 Погода",
+            Payload::Weather(WeatherPayload::Show { entity: 0, daily: false }).to_string()
+        )],
+
+        vec![
+            InlineKeyboardButton::callback(
+                "📅 Календарь",
+                Payload::Calendar(CalendarPayload::Upcoming { week: false }).to_string()
+            ),
+            InlineKeyboardButton::callback(
+                "📝 Списки дел",
+                Payload::Todo(TodoPayload::Lists).to_string()
+            ),
+        ],
+
         vec![InlineKeyboardButton::callback(
-            "🏠 Управление",
-            Payload::Control(ControlPayload::ListRooms).to_string()
+            "🚪 Ухожу из дома",
+            Payload::Control(ControlPayload::LeaveHome { confirmed: false }).to_string()
         )],
 
         vec![InlineKeyboardButton::callback(
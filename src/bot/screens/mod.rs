@@ -2,6 +2,12 @@ pub(crate) mod home;
 pub(crate) mod rooms;
 pub(crate) mod control;
 pub(crate) mod common;
-mod admin;
+pub(crate) mod admin;
 pub(crate) mod settings;
-pub(crate) mod room;
\ No newline at end of file
+pub(crate) mod room;
+pub(crate) mod presence;
+pub(crate) mod weather;
+pub(crate) mod calendar;
+pub(crate) mod todo;
+pub(crate) mod pagination;
+pub(crate) mod search;
@@ -0,0 +1,60 @@
+use teloxide::types::InlineKeyboardButton;
+
+use crate::bot::router::Payload;
+
+/// Строк списка на одной странице. Telegram ограничивает размер инлайн-клавиатуры,
+/// а длинные списки неудобно листать.
+pub const PAGE_SIZE: usize = 8;
+
+/// Строки страницы `page` и строка навигации «‹ n/m ›», если страниц больше одной.
+/// Элемент списка - блок строк, который не разрывается между страницами.
+/// Номер страницы ограничивается диапазоном (список мог сократиться) и возвращается вторым значением.
+fn paginate_pages(
+    blocks: Vec<Vec<Vec<InlineKeyboardButton>>>,
+    page: u8,
+    base: &Payload,
+) -> (Vec<Vec<InlineKeyboardButton>>, u8) {
+    // Номер страницы хранится в u8: дальше 256-й страницы список не листается
+    let pages = blocks.len().div_ceil(PAGE_SIZE).clamp(1, u8::MAX as usize + 1);
+    let page = (page as usize).min(pages - 1);
+
+    let mut result: Vec<_> = blocks.into_iter().skip(page * PAGE_SIZE).take(PAGE_SIZE).flatten().collect();
+
+    if pages > 1 {
+        let button = |label: String, p: usize| InlineKeyboardButton::callback(label, base.clone().paged(p as u8).to_string());
+
+        let mut nav_row = vec![];
+        if page > 0 {
+            nav_row.push(button("‹".into(), page - 1));
+        }
+        nav_row.push(button(format!("{}/{}", page + 1, pages), page));
+        if page + 1 < pages {
+            nav_row.push(button("›".into(), page + 1));
+        }
+        result.push(nav_row);
+    }
+
+    (result, page as u8)
+}
+
+/// Постраничный список: номер страницы хранится в `Payload::Page`.
+/// Возвращает строки и payload текущей страницы для `View`.
+pub fn paginate(
+    rows: Vec<Vec<InlineKeyboardButton>>,
+    page: u8,
+    base: &Payload,
+) -> (Vec<Vec<InlineKeyboardButton>>, Payload) {
+    let (rows, page) = paginate_pages(rows.into_iter().map(|row| vec![row]).collect(), page, base);
+    (rows, base.clone().paged(page))
+}
+
+/// `paginate` для блоков строк: например, заголовок группы вместе с ее первым устройством,
+/// чтобы страница не заканчивалась голым заголовком.
+pub fn paginate_blocks(
+    blocks: Vec<Vec<Vec<InlineKeyboardButton>>>,
+    page: u8,
+    base: &Payload,
+) -> (Vec<Vec<InlineKeyboardButton>>, Payload) {
+    let (rows, page) = paginate_pages(blocks, page, base);
+    (rows, base.clone().paged(page))
+}
@@ -0,0 +1,110 @@
+use teloxide::types::{InlineKeyboardButton, InlineKeyboardMarkup};
+use anyhow::Result;
+
+use crate::bot::models::{LocationShare, View};
+use crate::bot::router::{Payload, PresencePayload, RenderContext};
+use crate::core::presentation::StateFormatter;
+use crate::core::short_key;
+use crate::ha::models::Entity;
+
+pub async fn render(ctx: RenderContext, payload: PresencePayload) -> Result<View> {
+    let ha = &ctx.config.ha_client;
+
+    let mut persons = ha.fetch_states_by_domain("person").await?;
+    persons.sort_by(|a, b| a.entity_id.cmp(&b.entity_id));
+
+    // Батарея берется из трекера-источника (обычно device_tracker мобильного приложения)
+    let sources: Vec<String> = persons.iter().filter_map(source_tracker).collect();
+    let trackers = ha.fetch_states_by_ids(&sources).await.unwrap_or_default();
+
+    let mut lines = Vec::new();
+    let mut rows = Vec::new();
+
+    for person in &persons {
+        let name = person_name(person);
+        let icon = StateFormatter::get_icon("person", "", &person.state);
+        let zone = StateFormatter::translate_state(&person.state);
+
+        let mut line = format!("{} {} — {}", icon, name, zone);
+
+        if let Some(changed) = person.last_changed {
+            line.push_str(&format!(" • {}", StateFormatter::format_last_update(changed)));
+        }
+
+        let battery = source_tracker(person)
+            .and_then(|src| trackers.iter().find(|t| t.entity_id == src))
+            .and_then(|t| t.attributes.get("battery_level").and_then(|v| v.as_f64()));
+        if let Some(level) = battery {
+            line.push_str(&format!(" • 🔋 {:.0}%", level));
+        }
+
+        lines.push(line);
+
+        rows.push(vec![InlineKeyboardButton::callback(
+            format!("📍 {}", name),
+            Payload::Presence(PresencePayload::Locate { person: short_key(&person.entity_id) }).paged(ctx.page).to_string()
+        )]);
+    }
+
+    let (mut rows, current_payload) = crate::bot::screens::pagination::paginate(
+        rows, ctx.page, &Payload::Presence(PresencePayload::List)
+    );
+    rows.push(vec![crate::bot::screens::common::back_button(Payload::Home)]);
+
+    let mut alert = None;
+    let mut location = None;
+
+    if let PresencePayload::Locate { person } = payload {
+        // Ищем по ключу entity_id: список мог измениться с момента отрисовки
+        match persons.iter().find(|p| short_key(&p.entity_id) == person) {
+            Some(p) => match coordinates(p) {
+                Some((latitude, longitude)) => {
+                    location = Some(LocationShare {
+                        latitude,
+                        longitude,
+                        title: person_name(p),
+                        address: format!(
+                            "{} • {}",
+                            StateFormatter::translate_state(&p.state),
+                            p.last_changed.map(StateFormatter::format_last_update).unwrap_or_default()
+                        ),
+                    });
+                }
+                None => alert = Some(format!("Нет координат для «{}»", person_name(p))),
+            },
+            None => alert = Some("Человек не найден, список обновлен".to_string()),
+        }
+    }
+
+    let text = if lines.is_empty() {
+        "👥 Кто дома\n\nВ Home Assistant нет сущностей person.".to_string()
+    } else {
+        format!("👥 Кто дома\n\n{}\n\nНажмите на имя, чтобы получить точку на карте.", lines.join("\n"))
+    };
+
+    Ok(View {
+        notifications: ctx.notifications,
+        text,
+        kb: InlineKeyboardMarkup::new(rows),
+        payload: current_payload,
+        alert,
+        location,
+        ..Default::default()
+    })
+}
+
+fn person_name(person: &Entity) -> String {
+    person.friendly_name.clone()
+        .filter(|n| !n.is_empty())
+        .unwrap_or_else(|| person.entity_id.clone())
+}
+
+fn source_tracker(person: &Entity) -> Option<String> {
+    person.attributes.get("source").and_then(|v| v.as_str()).map(String::from)
+}
+
+fn coordinates(person: &Entity) -> Option<(f64, f64)> {
+    let lat = person.attributes.get("latitude").and_then(|v| v.as_f64())?;
+    let lon = person.attributes.get("longitude").and_then(|v| v.as_f64())?;
+    Some((lat, lon))
+}
@@ -1,8 +1,8 @@
 use crate::bot::models::View;
-use crate::bot::router::{ControlPayload, DeviceCmd, Payload, RenderContext, SettingsPayload};
+use crate::bot::router::{BulkAction, ControlPayload, DeviceCmd, Payload, RenderContext, SettingsPayload};
 
-use crate::core::devices::{SmartDevice, SmartEntity};
-use crate::bot::screens::common;
+use crate::core::kinds;
+use crate::bot::screens::{common, pagination};
 use crate::db;
 use anyhow::{Context, Result};
 use teloxide::types::{InlineKeyboardButton, InlineKeyboardMarkup};
@@ -25,45 +25,78 @@ pub async fn render(ctx: RenderContext, room_id: i64, mode: RoomViewMode) -> Res
     let entity_ids: Vec<String> = db_devices.iter().map(|d| d.entity_id.clone()).collect();
     let ha_entities = ctx.config.ha_client.fetch_states_by_ids(&entity_ids).await?;
 
-    let mut rows = vec![];
+    let base_payload = match mode {
+        RoomViewMode::Control => Payload::Control(ControlPayload::RoomDetail { room: room_id }),
+        RoomViewMode::Settings => Payload::Settings(SettingsPayload::RoomDetail { room: room_id }),
+    };
+
+    let mut cells = vec![];
+    // Сущности, которые видит пользователь: по ним же групповые действия выбирают цели
+    let mut visible = vec![];
 
     for db_dev in db_devices {
-        if db::subscriptions::is_hidden(db_dev.entity_id.as_str(), &ctx.config.db).await.unwrap_or(false) && mode == RoomViewMode::Control {
+        if db::subscriptions::is_hidden_for(ctx.user_id, db_dev.entity_id.as_str(), &ctx.config.db).await.unwrap_or(false) && mode == RoomViewMode::Control {
             continue;
         }
 
         if let Some(ha_ent) = ha_entities.iter().find(|e| e.entity_id == db_dev.entity_id) {
+            visible.push(ha_ent);
 
-            let smart_dev = SmartDevice::new(ha_ent.clone());
             let alias = db_dev.alias.as_deref().unwrap_or(&db_dev.entity_id);
-
-            let text = match mode {
-                RoomViewMode::Control => smart_dev.render_button_text_with_state(alias),
-                RoomViewMode::Settings => smart_dev.render_button_text(alias)
-            };
+            let state_alias = ctx.config.state_alias(&ha_ent.entity_id, &ha_ent.state);
+            let mut text = kinds::button_label(ha_ent, alias, state_alias.as_deref(), mode == RoomViewMode::Control);
+            // Команда отправлена, но HA еще не подтвердил смену состояния
+            if mode == RoomViewMode::Control && ctx.config.confirmations.is_pending(&db_dev.entity_id) {
+                text.push_str(" ⏳");
+            }
 
             let payload = match mode {
-                RoomViewMode::Control => {
-                    let domain = db_dev.entity_id.split('.').next().unwrap_or("");
-                    if domain == "climate" {
-                        Payload::Control(ControlPayload::DeviceControl { room: room_id, device: db_dev.id })
-                    } else {
-                        Payload::Control(ControlPayload::QuickAction {
-                            room: room_id,
-                            device: db_dev.id,
-                            cmd: DeviceCmd::Toggle
-                        })
-                    }
-                }
+                // Быстрое действие перерисовывает комнату: остаемся на той же странице
+                RoomViewMode::Control => match kinds::for_entity(ha_ent).room_cmd() {
+                    Some(cmd) => Payload::Control(ControlPayload::QuickAction {
+                        room: room_id,
+                        device: db_dev.id,
+                        cmd
+                    }).paged(ctx.page),
+                    None => Payload::Control(ControlPayload::DeviceControl { room: room_id, device: db_dev.id }),
+                },
                 RoomViewMode::Settings => {
                     Payload::Settings(SettingsPayload::DeviceDetail { room: room_id, device: db_dev.id })
                 }
             };
 
-            rows.push(vec![InlineKeyboardButton::callback(text, payload.to_string())]);
+            let mut row = vec![InlineKeyboardButton::callback(text, payload.to_string())];
+            // Порядок устройств общий для всех пользователей
+            if mode == RoomViewMode::Settings && ctx.is_admin {
+                let move_button = |label: &str, up: bool| InlineKeyboardButton::callback(
+                    label,
+                    Payload::Settings(SettingsPayload::MoveDevice { room: room_id, device: db_dev.id, up }).paged(ctx.page).to_string()
+                );
+                row.push(move_button("⬆", true));
+                row.push(move_button("⬇", false));
+            }
+
+            let compact = mode == RoomViewMode::Control
+                && room.compact_sensors
+                && matches!(kinds::entity_domain(ha_ent), "sensor" | "binary_sensor");
+            cells.push(Cell { group: db_dev.group_name, row, compact });
         }
     }
 
+    let blocks = layout_sections(cells, &base_payload.clone().paged(ctx.page));
+    let (mut rows, current_payload) = pagination::paginate_blocks(blocks, ctx.page, &base_payload);
+
+    if mode == RoomViewMode::Control {
+        rows.extend(bulk_rows(room_id, &visible));
+    }
+
+    if mode == RoomViewMode::Settings && ctx.is_admin {
+        rows.push(vec![InlineKeyboardButton::callback(
+            "🛠 Параметры комнаты",
+            Payload::Settings(SettingsPayload::RoomOptions { room: room_id }).to_string(),
+        )]);
+    }
+
     let back_payload = match mode {
         RoomViewMode::Control => Payload::Control(ControlPayload::ListRooms),
         RoomViewMode::Settings => Payload::Settings(SettingsPayload::ListRooms),
@@ -74,10 +107,82 @@ pub async fn render(ctx: RenderContext, room_id: i64, mode: RoomViewMode) -> Res
         notifications: ctx.notifications,
         text: format!("{} {}", header_label, room_display),
         kb: InlineKeyboardMarkup::new(rows),
-        payload: match mode {
-            RoomViewMode::Control => Payload::Control(ControlPayload::RoomDetail { room: room_id }),
-            RoomViewMode::Settings => Payload::Settings(SettingsPayload::RoomDetail { room: room_id }),
-        },
+        payload: current_payload,
         ..Default::default()
     })
 }
+
+/// Кнопка устройства (в настройках - вместе с кнопками перемещения).
+struct Cell {
+    group: Option<String>,
+    row: Vec<InlineKeyboardButton>,
+    /// Датчик в компактной комнате: ставится по два в ряд.
+    compact: bool,
+}
+
+/// Раскладывает устройства по секциям: сначала без группы, затем группы в порядке их первого устройства.
+/// Каждая группа начинается со строки-разделителя. Элемент результата - блок строк для пагинации:
+/// разделитель идет одним блоком с первой строкой группы и не отрывается от нее.
+fn layout_sections(cells: Vec<Cell>, current: &Payload) -> Vec<Vec<Vec<InlineKeyboardButton>>> {
+    let mut groups: Vec<Option<String>> = vec![None];
+    for cell in &cells {
+        if !groups.contains(&cell.group) {
+            groups.push(cell.group.clone());
+        }
+    }
+
+    let mut blocks = vec![];
+    for group in groups {
+        let section: Vec<&Cell> = cells.iter().filter(|c| c.group == group).collect();
+        if section.is_empty() {
+            continue;
+        }
+
+        let mut rows = vec![];
+        let mut pending: Option<InlineKeyboardButton> = None;
+        for cell in section {
+            if !cell.compact {
+                rows.extend(pending.take().map(|b| vec![b]));
+                rows.push(cell.row.clone());
+                continue;
+            }
+            match pending.take() {
+                Some(left) => rows.push(vec![left, cell.row[0].clone()]),
+                None => pending = Some(cell.row[0].clone()),
+            }
+        }
+        rows.extend(pending.map(|b| vec![b]));
+
+        let mut rows = rows.into_iter();
+        if let Some(name) = &group {
+            // Разделитель ничего не делает: нажатие просто перерисовывает экран
+            let separator = vec![InlineKeyboardButton::callback(format!("── {} ──", name), current.to_string())];
+            blocks.push(std::iter::once(separator).chain(rows.next()).collect());
+        }
+        blocks.extend(rows.map(|row| vec![row]));
+    }
+    blocks
+}
+
+/// Групповые действия внизу комнаты. Кнопки показываются, только если в комнате есть подходящие устройства.
+fn bulk_rows(room_id: i64, entities: &[&crate::ha::models::Entity]) -> Vec<Vec<InlineKeyboardButton>> {
+    let bulk = |label: &str, action: BulkAction| InlineKeyboardButton::callback(
+        label,
+        Payload::Control(ControlPayload::RoomBulk { room: room_id, action }).to_string()
+    );
+
+    let has_lights = entities.iter().any(|e| kinds::entity_domain(e) == "light");
+    let has_switchable = entities.iter().any(|e| kinds::for_entity(e).supports(&DeviceCmd::TurnOff));
+
+    let mut rows = Vec::new();
+    if has_lights {
+        rows.push(vec![bulk("🌑 Погасить весь свет", BulkAction::LightsOff)]);
+    }
+    if has_switchable {
+        rows.push(vec![
+            bulk("⏹ Выключить все", BulkAction::AllOff),
+            bulk("▶️ Включить все", BulkAction::AllOn),
+        ]);
+    }
+    rows
+}
@@ -9,7 +9,11 @@ use crate::db;
 
 
 pub async fn render(ctx: RenderContext, mode: RoomViewMode) -> Result<View> {
-    let rooms = db::rooms::get_rooms(&ctx.config.db).await.unwrap_or_else(|_| Vec::new());
+    // В настройках видны и скрытые комнаты, иначе их не вернуть
+    let rooms = match mode {
+        RoomViewMode::Control => db::rooms::get_rooms(&ctx.config.db).await,
+        RoomViewMode::Settings => db::rooms::get_all_rooms(&ctx.config.db).await,
+    }.unwrap_or_else(|_| Vec::new());
 
     let text = crate::core::presentation::StateFormatter::get_rooms_header(&mode);
 
@@ -22,18 +26,35 @@ pub async fn render(ctx: RenderContext, mode: RoomViewMode) -> Result<View> {
                 Payload::Settings(SettingsPayload::RoomDetail { room: room.id }),
         };
 
+        let label = match room.hide {
+            true => format!("{} 🙈", room.display_name()),
+            false => room.display_name(),
+        };
+
         rows.push(vec![InlineKeyboardButton::callback(
-            room.display_name(),
+            label,
             callback_payload.to_string(),
         )]);
     }
 
-    rows.push(vec![crate::bot::screens::common::back_button(Payload::Home)]);
-
-    let current_payload = match mode {
+    let base_payload = match mode {
         RoomViewMode::Control => Payload::Control(ControlPayload::ListRooms),
         RoomViewMode::Settings => Payload::Settings(SettingsPayload::ListRooms),
     };
+    let (mut rows, current_payload) = crate::bot::screens::pagination::paginate(rows, ctx.page, &base_payload);
+
+    if mode == RoomViewMode::Settings {
+        rows.push(vec![InlineKeyboardButton::callback(
+            "⭐ Избранное",
+            Payload::Settings(SettingsPayload::Favorites).to_string(),
+        )]);
+        rows.push(vec![InlineKeyboardButton::callback(
+            "📍 Трекер геопозиции",
+            Payload::Settings(SettingsPayload::LocationTracker).to_string(),
+        )]);
+    }
+
+    rows.push(vec![crate::bot::screens::common::back_button(Payload::Home)]);
 
     Ok(View {
         notifications: ctx.notifications,
@@ -0,0 +1,76 @@
+use teloxide::types::{InlineKeyboardButton, InlineKeyboardMarkup};
+use anyhow::Result;
+
+use crate::bot::models::View;
+use crate::bot::router::{ControlPayload, Payload, RenderContext, SearchPayload};
+use crate::bot::State;
+use crate::core::{kinds, search};
+
+pub fn render_prompt(ctx: RenderContext) -> View {
+    let cancel_payload = match ctx.config.search_queries.contains_key(&ctx.user_id) {
+        true => Payload::Search(SearchPayload::Results),
+        false => Payload::Home,
+    };
+
+    View {
+        header: Some("⌨️ Ввод данных".into()),
+        text: "🔍 Введите часть названия устройства.\n\n\
+               Можно по-русски или латиницей, ошибка в букве не страшна.\n\
+               В любой момент доступна команда /find <текст>.".into(),
+        kb: InlineKeyboardMarkup::new(vec![vec![
+            InlineKeyboardButton::callback("❌ Отмена", cancel_payload.to_string())
+        ]]),
+        payload: cancel_payload,
+        next_state: Some(State::WaitingForSearch),
+        ..View::default()
+    }
+}
+
+/// Результаты последнего запроса пользователя: кнопки управления с комнатой в начале.
+pub async fn render(ctx: RenderContext) -> Result<View> {
+    let Some(query) = ctx.config.search_queries.get(&ctx.user_id).map(|q| q.value().clone()) else {
+        return Ok(render_prompt(ctx));
+    };
+
+    let hits = search::find_devices(&ctx.config, ctx.user_id, &query).await?;
+
+    let rows: Vec<_> = hits.iter().map(|hit| {
+        let dev = &hit.device;
+        let alias = dev.alias.as_deref().unwrap_or(&dev.entity_id);
+        let state_alias = ctx.config.state_alias(&dev.entity_id, &hit.entity.state);
+        let mut label = kinds::button_label(&hit.entity, alias, state_alias.as_deref(), true);
+        if !hit.room_name.is_empty() {
+            label = format!("{} • {}", hit.room_name, label);
+        }
+        if ctx.config.confirmations.is_pending(&dev.entity_id) {
+            label.push_str(" ⏳");
+        }
+
+        // Действие перерисовывает результаты: остаемся на той же странице
+        let payload = match kinds::for_entity(&hit.entity).room_cmd() {
+            Some(_) => Payload::Search(SearchPayload::Action { device: dev.id }).paged(ctx.page),
+            None => Payload::Control(ControlPayload::DeviceControl { room: dev.room_id, device: dev.id }),
+        };
+        vec![InlineKeyboardButton::callback(label, payload.to_string())]
+    }).collect();
+
+    let (mut rows, current_payload) = crate::bot::screens::pagination::paginate(
+        rows, ctx.page, &Payload::Search(SearchPayload::Results)
+    );
+    rows.push(vec![InlineKeyboardButton::callback("🔍 Новый поиск", Payload::Search(SearchPayload::Prompt).to_string())]);
+    rows.push(vec![crate::bot::screens::common::back_button(Payload::Home)]);
+
+    let text = match hits.len() {
+        0 => format!("🔍 По запросу «{}» ничего не найдено.", query),
+        n if n >= search::MAX_RESULTS => format!("🔍 «{}»: показаны первые {}, уточните запрос.", query, n),
+        n => format!("🔍 «{}»: найдено {}.", query, n),
+    };
+
+    Ok(View {
+        notifications: ctx.notifications,
+        text,
+        kb: InlineKeyboardMarkup::new(rows),
+        payload: current_payload,
+        ..Default::default()
+    })
+}
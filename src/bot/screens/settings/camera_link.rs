@@ -0,0 +1,58 @@
+use crate::bot::models::View;
+use crate::bot::router::{Payload, RenderContext, SettingsPayload};
+
+use anyhow::{Context, Result};
+use teloxide::types::{InlineKeyboardButton, InlineKeyboardMarkup};
+use crate::db;
+
+pub async fn render(ctx: RenderContext, room_id: i64, device_id: i64) -> Result<View> {
+    let db = &ctx.config.db;
+
+    let dev = db::devices::get_device_by_id(device_id, db).await?
+        .context("Device not found")?;
+    let linked = db::subscriptions::get_linked_camera(ctx.user_id as i64, &dev.entity_id, db).await.unwrap_or(None);
+    let cameras = db::devices::get_devices_by_domain("camera", db).await?;
+
+    let link = |camera: i64| Payload::Settings(SettingsPayload::LinkCamera {
+        room: room_id,
+        device: device_id,
+        camera,
+    }).to_string();
+
+    let mut rows = vec![];
+    for cam in &cameras {
+        let mark = if linked.as_deref() == Some(cam.entity_id.as_str()) { "🔘" } else { "⚪" };
+        rows.push(vec![InlineKeyboardButton::callback(
+            format!("{} 📷 {}", mark, cam.alias.as_deref().unwrap_or(&cam.entity_id)),
+            link(cam.id)
+        )]);
+    }
+
+    let none_mark = if linked.is_none() { "🔘" } else { "⚪" };
+    rows.push(vec![InlineKeyboardButton::callback(format!("{} Без снимка", none_mark), link(0))]);
+
+    let (mut rows, current_payload) = crate::bot::screens::pagination::paginate(
+        rows, ctx.page, &Payload::Settings(SettingsPayload::PickCamera { room: room_id, device: device_id })
+    );
+
+    rows.push(vec![crate::bot::screens::common::back_button(
+        Payload::Settings(SettingsPayload::DeviceDetail { room: room_id, device: device_id })
+    )]);
+
+    let text = if cameras.is_empty() {
+        "📷 Камеры не найдены.\nДобавьте камеру в комнату в Home Assistant.".to_string()
+    } else {
+        format!(
+            "📷 Снимок к уведомлению\n\nУстройство: {}\nВыберите камеру, снимок с которой будет прикреплен к уведомлению о срабатывании:",
+            dev.alias.as_deref().unwrap_or(&dev.entity_id)
+        )
+    };
+
+    Ok(View {
+        notifications: ctx.notifications,
+        text,
+        kb: InlineKeyboardMarkup::new(rows),
+        payload: current_payload,
+        ..Default::default()
+    })
+}
@@ -0,0 +1,74 @@
+use crate::bot::models::View;
+use crate::bot::router::{Payload, RenderContext, SettingsPayload};
+use crate::bot::State;
+use crate::db;
+
+use anyhow::{Context, Result};
+use teloxide::types::{InlineKeyboardButton, InlineKeyboardMarkup};
+
+/// Значение `group` в `SetDeviceGroup`, убирающее устройство из группы.
+pub const NO_GROUP: u8 = u8::MAX;
+
+/// Выбор группы (секции) устройства внутри комнаты.
+pub async fn render(ctx: RenderContext, room_id: i64, device_id: i64) -> Result<View> {
+    let dev = db::devices::get_device_by_id(device_id, &ctx.config.db).await?
+        .context("Device not found")?;
+    let groups = db::devices::get_room_groups(room_id, &ctx.config.db).await?;
+
+    let button = |label: String, payload: SettingsPayload| {
+        InlineKeyboardButton::callback(label, Payload::Settings(payload).to_string())
+    };
+
+    let mut rows = vec![];
+    // Индекс группы передается в u8, поэтому выбор ограничен первыми 255 группами
+    for (index, group) in groups.iter().enumerate().take(NO_GROUP as usize) {
+        let mark = if dev.group_name.as_ref() == Some(group) { "✅ " } else { "" };
+        rows.push(vec![button(
+            format!("{}📂 {}", mark, group),
+            SettingsPayload::SetDeviceGroup { room: room_id, device: device_id, group: index as u8 },
+        )]);
+    }
+
+    let mark = if dev.group_name.is_none() { "✅ " } else { "" };
+    rows.push(vec![button(
+        format!("{}Без группы", mark),
+        SettingsPayload::SetDeviceGroup { room: room_id, device: device_id, group: NO_GROUP },
+    )]);
+    let (mut rows, current_payload) = crate::bot::screens::pagination::paginate(
+        rows, ctx.page, &Payload::Settings(SettingsPayload::PickDeviceGroup { room: room_id, device: device_id })
+    );
+    rows.push(vec![button(
+        "➕ Новая группа".to_string(),
+        SettingsPayload::NewDeviceGroup { room: room_id, device: device_id },
+    )]);
+    rows.push(vec![crate::bot::screens::common::back_button(
+        Payload::Settings(SettingsPayload::DeviceDetail { room: room_id, device: device_id })
+    )]);
+
+    Ok(View {
+        notifications: ctx.notifications,
+        text: format!(
+            "📂 Группа устройства {}\n\n\
+            Группы делят комнату на секции, например «Потолок» или «Датчики».",
+            dev.alias.as_deref().unwrap_or(&dev.entity_id)
+        ),
+        kb: InlineKeyboardMarkup::new(rows),
+        payload: current_payload,
+        ..Default::default()
+    })
+}
+
+pub fn render_input(room_id: i64, device_id: i64) -> View {
+    let cancel_payload = Payload::Settings(SettingsPayload::PickDeviceGroup { room: room_id, device: device_id });
+
+    View {
+        header: Some("⌨️ Ввод данных".into()),
+        text: "Введите название новой группы (до 32 символов).".into(),
+        kb: InlineKeyboardMarkup::new(vec![vec![
+            InlineKeyboardButton::callback("❌ Отмена", cancel_payload.to_string())
+        ]]),
+        payload: cancel_payload,
+        next_state: Some(State::WaitingForGroupName { device_id, room_id }),
+        ..View::default()
+    }
+}
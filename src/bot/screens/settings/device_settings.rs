@@ -1,5 +1,7 @@
 use crate::bot::models::View;
 use crate::bot::router::{Payload, RenderContext, SettingsPayload};
+use crate::core::devices::InputIntent;
+use crate::core::types::Device;
 
 use anyhow::{Context, Result};
 use teloxide::types::{InlineKeyboardButton, InlineKeyboardMarkup};
@@ -12,12 +14,25 @@ pub async fn render(ctx: RenderContext, room_id: i64, device_id: i64) -> Result<
         .context("Device not found")?;
 
     let subscribed = db::subscriptions::is_subscribed(ctx.user_id as i64, &dev.entity_id, db).await.unwrap_or(false);
-    let hidden = db::subscriptions::is_hidden(&dev.entity_id, db).await.unwrap_or(false);
+    let hidden = db::subscriptions::is_hidden_for(ctx.user_id, &dev.entity_id, db).await.unwrap_or(false);
+    let favorite = ctx.config.is_header_pinned(ctx.user_id, &dev.entity_id);
 
     let ha_ent = ctx.config.ha_client.fetch_states_by_ids(&[dev.entity_id.clone()]).await?
         .into_iter().next().context("HA offline")?;
 
-    let status_text = crate::core::presentation::StateFormatter::translate_state(&ha_ent.state);
+    let status_text = ctx.config.state_alias(&dev.entity_id, &ha_ent.state)
+        .unwrap_or_else(|| crate::core::presentation::StateFormatter::translate_state(&ha_ent.state).to_string());
+
+    let state_labels = match ctx.config.state_aliases.get(&dev.entity_id) {
+        Some(aliases) if !aliases.is_empty() => {
+            let mut lines: Vec<String> = aliases.iter()
+                .map(|(orig, human)| format!("`{}` → {}", orig, human))
+                .collect();
+            lines.sort();
+            format!("Подписи состояний:\n{}\n", lines.join("\n"))
+        }
+        _ => String::new(),
+    };
 
     let text = format!(
         "⚙️ Параметры\n\n\
@@ -25,11 +40,13 @@ pub async fn render(ctx: RenderContext, room_id: i64, device_id: i64) -> Result<
         Имя: `{}`\n\
         ID: `{}`\n\
         Статус: {}\n\
+        {}\
         ────────────────────\n\
         Настройте поведение устройства в боте:",
         dev.alias.as_deref().unwrap_or(&dev.entity_id),
         dev.entity_id,
-        status_text
+        status_text,
+        state_labels
     );
 
     let mut rows = vec![];
@@ -40,17 +57,62 @@ pub async fn render(ctx: RenderContext, room_id: i64, device_id: i64) -> Result<
         Payload::Settings(SettingsPayload::ToggleNotify { room: room_id, device: device_id }).to_string()
     )]);
 
+    // Снимок с камеры к уведомлению (движение, двери, дверной звонок)
+    if subscribed && crate::core::notification::supports_camera_snapshot(&dev.device_domain, &dev.device_class) {
+        let camera = db::subscriptions::get_linked_camera(ctx.user_id as i64, &dev.entity_id, db).await.unwrap_or(None);
+        let camera_label = match camera {
+            Some(eid) => ctx.config.name_aliases.get(&eid).map(|r| r.value().clone()).unwrap_or(eid),
+            None => "нет".to_string(),
+        };
+        rows.push(vec![InlineKeyboardButton::callback(
+            format!("📷 Снимок: {}", camera_label),
+            Payload::Settings(SettingsPayload::PickCamera { room: room_id, device: device_id }).to_string()
+        )]);
+    }
+
     let (hide_icon, hide_label) = if hidden { ("👁", "Показать в управлении") } else { ("🚫", "Скрыть из управления") };
     rows.push(vec![InlineKeyboardButton::callback(
         format!("{} {}", hide_icon, hide_label),
         Payload::Settings(SettingsPayload::ToggleHide { room: room_id, device: device_id }).to_string()
     )]);
 
+    // Значение по умолчанию для всех; личные настройки пользователей при смене сбрасываются
+    if ctx.is_admin {
+        let hidden_default = db::subscriptions::is_hidden(&dev.entity_id, db).await.unwrap_or(false);
+        let label = if hidden_default { "👥 Показать всем" } else { "👥 Скрыть для всех" };
+        rows.push(vec![InlineKeyboardButton::callback(
+            label,
+            Payload::Settings(SettingsPayload::ToggleHideGlobal { room: room_id, device: device_id }).to_string()
+        )]);
+    }
+
+    let (fav_icon, fav_label) = if favorite { ("★", "Убрать из избранного") } else { ("⭐", "В избранное") };
     rows.push(vec![InlineKeyboardButton::callback(
-        "✏️ Изменить имя",
-        Payload::Settings(SettingsPayload::EditName { room: room_id, device: device_id }).to_string()
+        format!("{} {}", fav_icon, fav_label),
+        Payload::Settings(SettingsPayload::ToggleFavorite { room: room_id, device: device_id }).to_string()
     )]);
 
+    // Имя и подписи состояний общие для всех пользователей - меняет только админ
+    if ctx.is_admin {
+        rows.push(vec![InlineKeyboardButton::callback(
+            "✏️ Изменить имя",
+            Payload::Settings(SettingsPayload::EditName { room: room_id, device: device_id }).to_string()
+        )]);
+
+        rows.push(vec![InlineKeyboardButton::callback(
+            format!("📂 Группа: {}", dev.group_name.as_deref().unwrap_or("нет")),
+            Payload::Settings(SettingsPayload::PickDeviceGroup { room: room_id, device: device_id }).to_string()
+        )]);
+    }
+
+    // Подпись задается для текущего состояния; числовые значения не подписываются
+    if ctx.is_admin && ha_ent.state.parse::<f64>().is_err() && !matches!(ha_ent.state.as_str(), "unavailable" | "unknown") {
+        rows.push(vec![InlineKeyboardButton::callback(
+            format!("🏷 Подпись для «{}»", ha_ent.state),
+            Payload::Settings(SettingsPayload::EditStateAlias { room: room_id, device: device_id }).to_string()
+        )]);
+    }
+
     // Кнопка "Назад"
     rows.push(vec![InlineKeyboardButton::callback(
         "⬅️ Назад к списку",
@@ -65,4 +127,42 @@ pub async fn render(ctx: RenderContext, room_id: i64, device_id: i64) -> Result<
         payload: Payload::Settings(SettingsPayload::DeviceDetail { room: room_id, device: device_id }),
         ..Default::default()
     })
-}
\ No newline at end of file
+}
+
+pub fn render_name_input(room_id: i64, dev: &Device) -> View {
+    let cancel_payload = Payload::Settings(SettingsPayload::DeviceDetail { room: room_id, device: dev.id });
+
+    View {
+        header: Some("⌨️ Ввод данных".into()),
+        text: format!(
+            "Текущее имя: {}\n\nВведите новое имя устройства (до 64 символов).",
+            dev.alias.as_deref().unwrap_or(&dev.entity_id)
+        ),
+        kb: InlineKeyboardMarkup::new(vec![vec![
+            InlineKeyboardButton::callback("❌ Отмена", cancel_payload.to_string())
+        ]]),
+        payload: cancel_payload,
+        next_state: Some(InputIntent::RenameDevice { device_id: dev.id, room_id }.into()),
+        ..View::default()
+    }
+}
+
+pub fn render_state_alias_input(room_id: i64, device_id: i64, original_state: String) -> View {
+    let cancel_payload = Payload::Settings(SettingsPayload::DeviceDetail { room: room_id, device: device_id });
+
+    View {
+        header: Some("⌨️ Ввод данных".into()),
+        text: format!(
+            "Введите подпись для состояния «{}» (до 32 символов).\n\n\
+            Она заменит значение на кнопках, в шапке и в уведомлениях. \
+            Отправьте «-», чтобы убрать подпись.",
+            original_state
+        ),
+        kb: InlineKeyboardMarkup::new(vec![vec![
+            InlineKeyboardButton::callback("❌ Отмена", cancel_payload.to_string())
+        ]]),
+        payload: cancel_payload,
+        next_state: Some(InputIntent::SetStateAlias { device_id, room_id, original_state }.into()),
+        ..View::default()
+    }
+}
@@ -0,0 +1,69 @@
+use crate::bot::models::View;
+use crate::bot::router::{Payload, RenderContext, SettingsPayload};
+use crate::core::presentation::StateFormatter;
+use crate::core::short_key;
+
+use anyhow::Result;
+use teloxide::types::{InlineKeyboardButton, InlineKeyboardMarkup};
+
+/// Избранное пользователя: порядок на главном экране и в шапке.
+pub async fn render(ctx: RenderContext) -> Result<View> {
+    let pinned = ctx.config.get_pinned(ctx.user_id);
+    let entities = ctx.config.ha_client.fetch_states_by_ids(&pinned).await.unwrap_or_default();
+
+    let mut rows = vec![];
+    for entity_id in &pinned {
+        let key = short_key(entity_id);
+        let name = ctx.config.name_aliases.get(entity_id)
+            .map(|r| r.value().clone())
+            .unwrap_or_else(|| entity_id.clone());
+
+        let icon = match entities.iter().find(|e| &e.entity_id == entity_id) {
+            Some(entity) => StateFormatter::get_icon(
+                entity_id.split('.').next().unwrap_or(""),
+                entity.device_class.as_deref().unwrap_or(""),
+                &entity.state,
+            ),
+            None => "❔",
+        };
+
+        let button = |label: &str, payload: SettingsPayload| {
+            InlineKeyboardButton::callback(label, Payload::Settings(payload).paged(ctx.page).to_string())
+        };
+        rows.push(vec![
+            button(&format!("{} {}", icon, name), SettingsPayload::Favorites),
+            button("⬆", SettingsPayload::MoveFavorite { key, up: true }),
+            button("⬇", SettingsPayload::MoveFavorite { key, up: false }),
+            button("✖", SettingsPayload::RemoveFavorite { key }),
+        ]);
+    }
+
+    let (mut rows, current_payload) = crate::bot::screens::pagination::paginate(
+        rows, ctx.page, &Payload::Settings(SettingsPayload::Favorites)
+    );
+    rows.push(vec![crate::bot::screens::common::back_button(
+        Payload::Settings(SettingsPayload::ListRooms)
+    )]);
+
+    let text = if pinned.is_empty() {
+        "⭐ Избранное\n\n\
+        Пока пусто. Отметьте устройство звездочкой в его настройках, \
+        и оно появится на главном экране и в шапке.".to_string()
+    } else {
+        "⭐ Избранное\n\n\
+        Порядок кнопок на главном экране и строк в шапке:".to_string()
+    };
+
+    Ok(View {
+        notifications: ctx.notifications,
+        text,
+        kb: InlineKeyboardMarkup::new(rows),
+        payload: current_payload,
+        ..Default::default()
+    })
+}
+
+/// Закрепленная сущность по короткому ключу из callback data.
+pub fn find_pinned(ctx: &RenderContext, key: u32) -> Option<String> {
+    ctx.config.get_pinned(ctx.user_id).into_iter().find(|e| short_key(e) == key)
+}
@@ -0,0 +1,64 @@
+use crate::bot::models::View;
+use crate::bot::router::{Payload, RenderContext, SettingsPayload};
+use crate::bot::State;
+
+use anyhow::Result;
+use teloxide::types::{InlineKeyboardButton, InlineKeyboardMarkup};
+use crate::db;
+
+pub async fn render(ctx: RenderContext) -> Result<View> {
+    let dev_id = db::location_trackers::get_dev_id(ctx.user_id, &ctx.config.db).await?;
+
+    let mut rows = vec![vec![InlineKeyboardButton::callback(
+        "✏️ Задать dev_id",
+        Payload::Settings(SettingsPayload::EditLocationTracker).to_string()
+    )]];
+
+    let status = match &dev_id {
+        Some(id) => {
+            rows.push(vec![InlineKeyboardButton::callback(
+                "🗑 Отключить",
+                Payload::Settings(SettingsPayload::ClearLocationTracker).to_string()
+            )]);
+            format!("device_tracker.{}", id)
+        }
+        None => "не настроен".to_string(),
+    };
+
+    rows.push(vec![crate::bot::screens::common::back_button(
+        Payload::Settings(SettingsPayload::ListRooms)
+    )]);
+
+    let text = format!(
+        "📍 Трекер геопозиции\n\n\
+        Трекер: {}\n\
+        ────────────────────\n\
+        Отправьте боту геопозицию (или включите трансляцию), \
+        и она будет передана в Home Assistant через device_tracker.see.",
+        status
+    );
+
+    Ok(View {
+        notifications: ctx.notifications,
+        text,
+        kb: InlineKeyboardMarkup::new(rows),
+        payload: Payload::Settings(SettingsPayload::LocationTracker),
+        ..Default::default()
+    })
+}
+
+pub fn render_input() -> View {
+    let cancel_payload = Payload::Settings(SettingsPayload::LocationTracker);
+
+    View {
+        header: Some("⌨️ Ввод данных".into()),
+        text: "Введите dev_id трекера: латиница в нижнем регистре, цифры и _.\n\n\
+               Например: tg_anna — в HA появится device_tracker.tg_anna.".into(),
+        kb: InlineKeyboardMarkup::new(vec![vec![
+            InlineKeyboardButton::callback("❌ Отмена", cancel_payload.to_string())
+        ]]),
+        payload: cancel_payload,
+        next_state: Some(State::WaitingForTrackerId),
+        ..View::default()
+    }
+}
@@ -1 +1,6 @@
-pub(crate) mod device_settings;
\ No newline at end of file
+pub(crate) mod device_settings;
+pub(crate) mod camera_link;
+pub(crate) mod location_tracker;
+pub(crate) mod favorites;
+pub(crate) mod room_settings;
+pub(crate) mod device_group;
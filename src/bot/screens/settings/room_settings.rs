@@ -0,0 +1,145 @@
+use crate::bot::models::View;
+use crate::bot::router::{Payload, RenderContext, SettingsPayload};
+use crate::bot::State;
+use crate::core::presentation::StateFormatter;
+use crate::db;
+
+use anyhow::{Context, Result};
+use teloxide::types::{InlineKeyboardButton, InlineKeyboardMarkup};
+
+/// Иконки для быстрого выбора. Индекс передается в `SettingsPayload::SetRoomIcon`.
+pub const PRESET_ICONS: [&str; 16] = [
+    "🛋", "🛌", "🍳", "🛀",
+    "🚽", "🖥", "🧸", "🧥",
+    "🚗", "🌳", "🧺", "🏠",
+    "🚪", "🎮", "🏋", "📚",
+];
+
+/// Значение `icon` в `SetRoomIcon`, сбрасывающее пользовательскую иконку.
+pub const RESET_ICON: u8 = u8::MAX;
+
+fn button(label: &str, payload: SettingsPayload) -> InlineKeyboardButton {
+    InlineKeyboardButton::callback(label, Payload::Settings(payload).to_string())
+}
+
+/// Параметры комнаты: название, иконка, видимость и место в списке.
+pub async fn render(ctx: RenderContext, room_id: i64) -> Result<View> {
+    let room = db::rooms::get_room_by_id(room_id, &ctx.config.db).await?
+        .context("Room not found")?;
+
+    let icon_source = if room.icon.is_some() {
+        "своя"
+    } else if room.ha_icon.as_deref().and_then(StateFormatter::map_mdi_room_icon).is_some() {
+        "из Home Assistant"
+    } else {
+        "по названию"
+    };
+
+    let text = format!(
+        "🛠 Параметры комнаты\n\n\
+        {}\n\
+        Зона HA: {}\n\
+        Иконка: {} ({})\n\
+        В списке комнат: {}",
+        room.display_name(),
+        room.area,
+        room.icon(),
+        icon_source,
+        if room.hide { "скрыта" } else { "показана" },
+    );
+
+    let rows = vec![
+        vec![
+            button("✏️ Переименовать", SettingsPayload::RenameRoom { room: room_id }),
+            button("🎨 Иконка", SettingsPayload::PickRoomIcon { room: room_id }),
+        ],
+        vec![button(
+            if room.hide { "👁 Показать комнату" } else { "🙈 Скрыть комнату" },
+            SettingsPayload::ToggleRoomHide { room: room_id },
+        )],
+        vec![button(
+            if room.compact_sensors { "▦ Датчики: по два в ряд" } else { "▤ Датчики: по одному в ряд" },
+            SettingsPayload::ToggleCompactSensors { room: room_id },
+        )],
+        vec![
+            button("⬆ Выше", SettingsPayload::MoveRoom { room: room_id, up: true }),
+            button("⬇ Ниже", SettingsPayload::MoveRoom { room: room_id, up: false }),
+        ],
+        vec![crate::bot::screens::common::back_button(
+            Payload::Settings(SettingsPayload::RoomDetail { room: room_id })
+        )],
+    ];
+
+    Ok(View {
+        notifications: ctx.notifications,
+        text,
+        kb: InlineKeyboardMarkup::new(rows),
+        payload: Payload::Settings(SettingsPayload::RoomOptions { room: room_id }),
+        ..Default::default()
+    })
+}
+
+pub async fn render_icon_picker(ctx: RenderContext, room_id: i64) -> Result<View> {
+    let room = db::rooms::get_room_by_id(room_id, &ctx.config.db).await?
+        .context("Room not found")?;
+
+    let mut rows: Vec<Vec<InlineKeyboardButton>> = PRESET_ICONS
+        .chunks(4)
+        .enumerate()
+        .map(|(row, icons)| {
+            icons.iter().enumerate()
+                .map(|(col, icon)| {
+                    let index = (row * 4 + col) as u8;
+                    button(icon, SettingsPayload::SetRoomIcon { room: room_id, icon: index })
+                })
+                .collect()
+        })
+        .collect();
+
+    rows.push(vec![button("⌨️ Свое эмодзи", SettingsPayload::EnterRoomIcon { room: room_id })]);
+    if room.icon.is_some() {
+        rows.push(vec![button("↺ По умолчанию", SettingsPayload::SetRoomIcon { room: room_id, icon: RESET_ICON })]);
+    }
+    rows.push(vec![crate::bot::screens::common::back_button(
+        Payload::Settings(SettingsPayload::RoomOptions { room: room_id })
+    )]);
+
+    Ok(View {
+        notifications: ctx.notifications,
+        text: format!("🎨 Иконка комнаты {}\n\nВыберите из списка или отправьте свое эмодзи.", room.display_name()),
+        kb: InlineKeyboardMarkup::new(rows),
+        payload: Payload::Settings(SettingsPayload::PickRoomIcon { room: room_id }),
+        ..Default::default()
+    })
+}
+
+pub fn render_name_input(room_id: i64) -> View {
+    render_input(
+        room_id,
+        "Введите новое название комнаты (до 64 символов).",
+        State::WaitingForRoomName { room_id },
+    )
+}
+
+pub fn render_icon_input(room_id: i64) -> View {
+    render_input(
+        room_id,
+        "Отправьте одно эмодзи для иконки комнаты.",
+        State::WaitingForRoomIcon { room_id },
+    )
+}
+
+fn render_input(room_id: i64, text: &str, next_state: State) -> View {
+    let cancel_payload = Payload::Settings(SettingsPayload::RoomOptions { room: room_id });
+
+    View {
+        header: Some("⌨️ Ввод данных".into()),
+        text: text.into(),
+        kb: InlineKeyboardMarkup::new(vec![vec![
+            InlineKeyboardButton::callback("❌ Отмена", cancel_payload.to_string())
+        ]]),
+        payload: cancel_payload,
+        next_state: Some(next_state),
+        ..View::default()
+    }
+}
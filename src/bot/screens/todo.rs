@@ -0,0 +1,144 @@
+use teloxide::types::{InlineKeyboardButton, InlineKeyboardMarkup};
+use anyhow::Result;
+
+use crate::bot::models::View;
+use crate::bot::router::{Payload, RenderContext, TodoPayload};
+use crate::bot::State;
+use crate::core::{calendar, short_key};
+use crate::ha::models::Entity;
+
+pub async fn render(ctx: RenderContext, payload: TodoPayload) -> Result<View> {
+    let mut lists = ctx.config.ha_client.fetch_states_by_domain("todo").await?;
+    lists.sort_by(|a, b| a.entity_id.cmp(&b.entity_id));
+
+    match payload {
+        TodoPayload::Lists => render_lists(ctx, &lists),
+        TodoPayload::List { list } => render_list(ctx, &lists, list, None).await,
+        TodoPayload::Check { list, item } => render_list(ctx, &lists, list, Some(item)).await,
+        TodoPayload::Add { list } => match find_list(&lists, list) {
+            Some(entity) => Ok(render_input(entity, list)),
+            None => render_list(ctx, &lists, list, None).await,
+        },
+    }
+}
+
+fn render_lists(ctx: RenderContext, lists: &[Entity]) -> Result<View> {
+    let rows: Vec<_> = lists.iter().map(|list| {
+        // Состояние todo-сущности - число невыполненных пунктов
+        vec![InlineKeyboardButton::callback(
+            format!("📝 {} ({})", list_name(list), list.state),
+            Payload::Todo(TodoPayload::List { list: short_key(&list.entity_id) }).to_string()
+        )]
+    }).collect();
+    let (mut rows, current_payload) = crate::bot::screens::pagination::paginate(
+        rows, ctx.page, &Payload::Todo(TodoPayload::Lists)
+    );
+    rows.push(vec![crate::bot::screens::common::back_button(Payload::Home)]);
+
+    let text = if lists.is_empty() {
+        "📝 Списки дел\n\nВ Home Assistant нет сущностей todo.".to_string()
+    } else {
+        "📝 Списки дел\n\nВыберите список:".to_string()
+    };
+
+    Ok(View {
+        header: Some("📝 Списки дел".into()),
+        notifications: ctx.notifications,
+        text,
+        kb: InlineKeyboardMarkup::new(rows),
+        payload: current_payload,
+        ..Default::default()
+    })
+}
+
+async fn render_list(ctx: RenderContext, lists: &[Entity], list: u32, check: Option<u32>) -> Result<View> {
+    // Ищем по ключу entity_id: перечень списков мог измениться с момента отрисовки
+    let Some(entity) = find_list(lists, list) else {
+        let mut view = render_lists(ctx, lists)?;
+        view.alert = Some("Список не найден, перечень обновлен".into());
+        return Ok(view);
+    };
+
+    let ha = &ctx.config.ha_client;
+    let mut alert = None;
+
+    let mut items = calendar::fetch_todo_items(ha, &entity.entity_id).await?;
+
+    if let Some(key) = check {
+        // Ищем по ключу uid, а не по позиции: список мог измениться с момента отрисовки
+        match items.iter().position(|i| i.key() == key) {
+            Some(pos) => match calendar::complete_todo_item(ha, &entity.entity_id, &items[pos].uid).await {
+                Ok(()) => { items.remove(pos); }
+                Err(e) => {
+                    log::error!("Todo item completion failed in {}: {}", entity.entity_id, e);
+                    alert = Some("Не удалось отметить пункт".to_string());
+                }
+            },
+            None => alert = Some("Пункт уже выполнен или удален".to_string()),
+        }
+    }
+
+    let rows: Vec<_> = items.iter().map(|item| {
+        let label = match &item.due {
+            Some(due) => format!("⬜ {} ⏰ {}", item.summary, due),
+            None => format!("⬜ {}", item.summary),
+        };
+        vec![InlineKeyboardButton::callback(
+            label,
+            // Отметка перерисовывает список: остаемся на той же странице
+            Payload::Todo(TodoPayload::Check { list, item: item.key() }).paged(ctx.page).to_string()
+        )]
+    }).collect();
+    let (mut rows, current_payload) = crate::bot::screens::pagination::paginate(
+        rows, ctx.page, &Payload::Todo(TodoPayload::List { list })
+    );
+
+    rows.push(vec![
+        InlineKeyboardButton::callback("➕ Добавить", Payload::Todo(TodoPayload::Add { list }).to_string()),
+        InlineKeyboardButton::callback("🔄 Обновить", Payload::Todo(TodoPayload::List { list }).to_string()),
+    ]);
+    rows.push(vec![crate::bot::screens::common::back_button(Payload::Todo(TodoPayload::Lists))]);
+
+    let mut text = format!("📝 {}\n\n", list_name(entity));
+    if items.is_empty() {
+        text.push_str("Все сделано 🎉");
+    } else {
+        text.push_str(&format!("Невыполнено: {}. Нажмите на пункт, чтобы отметить его.", items.len()));
+    }
+
+    Ok(View {
+        header: Some("📝 Списки дел".into()),
+        notifications: ctx.notifications,
+        text,
+        kb: InlineKeyboardMarkup::new(rows),
+        // Live-обновление не должно повторно отмечать пункт
+        payload: current_payload,
+        alert,
+        ..Default::default()
+    })
+}
+
+fn render_input(entity: &Entity, list: u32) -> View {
+    let cancel_payload = Payload::Todo(TodoPayload::List { list });
+
+    View {
+        header: Some("⌨️ Ввод данных".into()),
+        text: format!("Введите новый пункт для списка «{}».", list_name(entity)),
+        kb: InlineKeyboardMarkup::new(vec![vec![
+            InlineKeyboardButton::callback("❌ Отмена", cancel_payload.to_string())
+        ]]),
+        payload: cancel_payload,
+        next_state: Some(State::WaitingForTodoItem { list, entity_id: entity.entity_id.clone() }),
+        ..View::default()
+    }
+}
+
+fn find_list(lists: &[Entity], key: u32) -> Option<&Entity> {
+    lists.iter().find(|l| short_key(&l.entity_id) == key)
+}
+
+fn list_name(list: &Entity) -> String {
+    list.friendly_name.clone()
+        .filter(|n| !n.is_empty())
+        .unwrap_or_else(|| list.entity_id.clone())
+}
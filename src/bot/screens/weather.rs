@@ -0,0 +1,110 @@
+use teloxide::types::{InlineKeyboardButton, InlineKeyboardMarkup};
+use anyhow::Result;
+
+use crate::bot::models::View;
+use crate::bot::router::{Payload, RenderContext, WeatherPayload};
+use crate::core::{short_key, weather};
+
+pub async fn render(ctx: RenderContext, payload: WeatherPayload) -> Result<View> {
+    let ha = &ctx.config.ha_client;
+
+    let mut entities = ha.fetch_states_by_domain("weather").await?;
+    entities.sort_by(|a, b| a.entity_id.cmp(&b.entity_id));
+
+    let (key, daily) = match payload {
+        WeatherPayload::Show { entity, daily } | WeatherPayload::TogglePin { entity, daily } => (entity, daily),
+    };
+
+    let back_row = vec![crate::bot::screens::common::back_button(Payload::Home)];
+
+    // Ищем по ключу entity_id; неизвестный ключ (в том числе вход с главного экрана) - первая сущность
+    let found = entities.iter().position(|e| short_key(&e.entity_id) == key);
+    let index = found.unwrap_or(0);
+    let Some(entity) = entities.get(index) else {
+        return Ok(View {
+            header: Some("🌤 Погода".into()),
+            notifications: ctx.notifications,
+            text: "В Home Assistant нет сущностей weather.".into(),
+            kb: InlineKeyboardMarkup::new(vec![back_row]),
+            payload: Payload::Weather(WeatherPayload::Show { entity: 0, daily }),
+            ..Default::default()
+        });
+    };
+
+    let mut alert = None;
+    let mut notifications = ctx.notifications;
+
+    if let WeatherPayload::TogglePin { .. } = payload {
+        // Сущность исчезла с момента отрисовки - не закрепляем вместо нее другую
+        if found.is_none() {
+            alert = Some("Сущность не найдена, список обновлен".to_string());
+        } else {
+            match ctx.config.toggle_header_pin(ctx.user_id, &entity.entity_id).await {
+                // Шапка собрана до переключения - пересобираем
+                Ok(_) => notifications = ctx.config.get_header_data(ctx.user_id).await,
+                Err(e) => {
+                    log::error!("Weather pin toggle failed for {}: {}", entity.entity_id, e);
+                    alert = Some("Не удалось изменить закрепление".to_string());
+                }
+            }
+        }
+    }
+
+    let name = weather::display_name(entity);
+    let mut lines = vec![format!("🌤 {}", name), weather::current_summary(entity)];
+    lines.extend(weather::current_details(entity));
+
+    let title = format!("{} • {}", name, if daily { "прогноз по дням" } else { "почасовой прогноз" });
+    let image = match weather::fetch_forecast(ha, &entity.entity_id, daily).await {
+        Ok(points) => match crate::charts::draw_forecast_chart(&points, &title, daily) {
+            Ok(png) => Some(png),
+            Err(e) => {
+                log::error!("Forecast chart failed for {}: {}", entity.entity_id, e);
+                None
+            }
+        },
+        Err(e) => {
+            log::warn!("Forecast unavailable for {}: {}", entity.entity_id, e);
+            lines.push(String::new());
+            lines.push("Прогноз недоступен для этой сущности.".into());
+            None
+        }
+    };
+
+    let entity_key = short_key(&entity.entity_id);
+    let show = |entity: u32, daily: bool| Payload::Weather(WeatherPayload::Show { entity, daily }).to_string();
+
+    let mut rows = vec![vec![
+        InlineKeyboardButton::callback(if daily { "⏱ По часам" } else { "✅ По часам" }, show(entity_key, false)),
+        InlineKeyboardButton::callback(if daily { "✅ По дням" } else { "📅 По дням" }, show(entity_key, true)),
+    ]];
+
+    if entities.len() > 1 {
+        let prev = (index + entities.len() - 1) % entities.len();
+        let next = (index + 1) % entities.len();
+        rows.push(vec![
+            InlineKeyboardButton::callback("‹", show(short_key(&entities[prev].entity_id), daily)),
+            InlineKeyboardButton::callback(format!("{}/{}", index + 1, entities.len()), show(entity_key, daily)),
+            InlineKeyboardButton::callback("›", show(short_key(&entities[next].entity_id), daily)),
+        ]);
+    }
+
+    let pinned = ctx.config.is_header_pinned(ctx.user_id, &entity.entity_id);
+    rows.push(vec![InlineKeyboardButton::callback(
+        if pinned { "📌 Открепить из шапки" } else { "📌 Закрепить в шапке" },
+        Payload::Weather(WeatherPayload::TogglePin { entity: entity_key, daily }).to_string()
+    )]);
+    rows.push(back_row);
+
+    Ok(View {
+        header: Some("🌤 Погода".into()),
+        notifications,
+        text: lines.join("\n"),
+        kb: InlineKeyboardMarkup::new(rows),
+        // Live-обновление не должно повторно переключать закрепление
+        payload: Payload::Weather(WeatherPayload::Show { entity: entity_key, daily }),
+        alert,
+        image,
+        ..Default::default()
+    })
+}
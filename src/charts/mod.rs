@@ -11,6 +11,8 @@ const HA_TEXT: RGBColor = RGBColor(180, 180, 180);
 const HA_BLUE: RGBColor = RGBColor(93, 175, 243);
 const HA_BIN_ON: RGBColor = RGBColor(93, 175, 243);
 const HA_BIN_OFF: RGBColor = RGBColor(70, 70, 70);
+const HA_ORANGE: RGBColor = RGBColor(255, 152, 0);
+const HA_RAIN: RGBColor = RGBColor(68, 115, 158);
 
 #[derive(Clone, Copy)]
 pub enum ChartStyle {
@@ -48,6 +50,94 @@ pub fn draw_ha_chart(
     encode_png(&buffer, width, height)
 }
 
+/// Точка прогноза погоды для графика.
+#[derive(Debug, Clone)]
+pub struct ForecastPoint {
+    pub time: DateTime<Utc>,
+    pub temperature: f64,
+    /// Минимальная температура (есть только в дневном прогнозе).
+    pub templow: Option<f64>,
+    pub precipitation: f64,
+}
+
+/// График прогноза: линия температуры и столбцы осадков (вторичная ось).
+pub fn draw_forecast_chart(points: &[ForecastPoint], title: &str, daily: bool) -> Result<Vec<u8>> {
+    if points.is_empty() {
+        return Err(anyhow!("Прогноз отсутствует"));
+    }
+
+    let (width, height) = (1000, 600);
+    let mut buffer = vec![0u8; width * height * 3];
+
+    {
+        let root = BitMapBackend::with_buffer(&mut buffer, (width as u32, height as u32)).into_drawing_area();
+        root.fill(&HA_BG)?;
+        render_forecast(&root, points, title, daily)?;
+        root.present()?;
+    }
+    encode_png(&buffer, width, height)
+}
+
+fn render_forecast<B: DrawingBackend>(
+    root: &DrawingArea<B, plotters::coord::Shift>,
+    points: &[ForecastPoint],
+    title: &str,
+    daily: bool,
+) -> Result<()> where B::ErrorType: 'static {
+    // Ширина слота - расстояние между соседними точками прогноза
+    let slot = match points {
+        [a, b, ..] => (b.time - a.time).max(Duration::minutes(1)),
+        _ => if daily { Duration::days(1) } else { Duration::hours(1) },
+    };
+    let x_min = points[0].time - slot / 2;
+    let x_max = points[points.len() - 1].time + slot / 2;
+
+    let temps = points.iter().flat_map(|p| std::iter::once(p.temperature).chain(p.templow));
+    let min_t = temps.clone().fold(f64::INFINITY, f64::min);
+    let max_t = temps.fold(f64::NEG_INFINITY, f64::max);
+    let range = (max_t - min_t).max(1.0);
+    let (y_min, y_max) = (min_t - range * 0.2, max_t + range * 0.2);
+
+    let max_p = points.iter().map(|p| p.precipitation).fold(0.0, f64::max).max(1.0) * 1.2;
+
+    let mut chart = ChartBuilder::on(root)
+        .caption(title, ("sans-serif", 25).into_font().color(&HA_TEXT))
+        .margin(30).x_label_area_size(80).y_label_area_size(60).right_y_label_area_size(60)
+        .build_cartesian_2d(x_min..x_max, y_min..y_max)?
+        .set_secondary_coord(x_min..x_max, 0.0..max_p);
+
+    let x_format = if daily { "%d.%m" } else { "%H:%M" };
+    chart.configure_mesh()
+        .x_labels(8).y_labels(6).disable_x_mesh()
+        .axis_style(HA_GRID).label_style(("sans-serif", 15).into_font().color(&HA_TEXT))
+        .x_label_formatter(&|x| x.with_timezone(&Local).format(x_format).to_string())
+        .y_label_formatter(&|y| format!("{:.0}°", y))
+        .draw()?;
+
+    chart.configure_secondary_axes()
+        .axis_style(HA_GRID).label_style(("sans-serif", 15).into_font().color(&HA_TEXT))
+        .y_label_formatter(&|y| format!("{:.1} мм", y))
+        .draw()?;
+
+    // Осадки - столбцы под линией температуры
+    chart.draw_secondary_series(points.iter().filter(|p| p.precipitation > 0.0).map(|p| {
+        Rectangle::new(
+            [(p.time - slot / 3, 0.0), (p.time + slot / 3, p.precipitation)],
+            HA_RAIN.mix(0.8).filled(),
+        )
+    }))?;
+
+    if points.iter().any(|p| p.templow.is_some()) {
+        let lows: Vec<_> = points.iter().filter_map(|p| p.templow.map(|t| (p.time, t))).collect();
+        chart.draw_series(LineSeries::new(lows, HA_BLUE.stroke_width(2)))?;
+    }
+
+    chart.draw_series(LineSeries::new(points.iter().map(|p| (p.time, p.temperature)), HA_ORANGE.stroke_width(3)))?;
+    chart.draw_series(points.iter().map(|p| Circle::new((p.time, p.temperature), 3, HA_ORANGE.filled())))?;
+
+    Ok(())
+}
+
 fn render_numeric<B: DrawingBackend>(
     root: &DrawingArea<B, plotters::coord::Shift>,
     data: &[(DateTime<Utc>, String)],
@@ -0,0 +1,110 @@
+//! Автоматизации HA: состояние, ручной запуск и сводка последнего запуска (WS `trace/list`).
+
+use anyhow::Result;
+use chrono::{DateTime, Utc};
+use serde_json::{json, Value};
+
+use crate::ha::models::Entity;
+use crate::ha::HAClient;
+
+/// Сводка по одному запуску автоматизации.
+#[derive(Debug, Clone)]
+pub struct TraceSummary {
+    pub start: DateTime<Utc>,
+    pub finish: Option<DateTime<Utc>>,
+    pub execution: Option<String>,
+    pub trigger: Option<String>,
+    pub last_step: Option<String>,
+    pub error: Option<String>,
+}
+
+impl TraceSummary {
+    pub fn duration_ms(&self) -> Option<i64> {
+        self.finish.map(|f| (f - self.start).num_milliseconds())
+    }
+}
+
+/// ID из конфигурации (`attributes.id`). Без него HA не хранит трассировки.
+pub fn config_id(entity: &Entity) -> Option<&str> {
+    entity.attributes.get("id").and_then(|v| v.as_str())
+}
+
+pub fn last_triggered(entity: &Entity) -> Option<DateTime<Utc>> {
+    entity.attributes.get("last_triggered")
+        .and_then(|v| v.as_str())
+        .and_then(|s| DateTime::parse_from_rfc3339(s).ok())
+        .map(|dt| dt.with_timezone(&Utc))
+}
+
+/// Последний запуск автоматизации по данным `trace/list`.
+pub async fn fetch_last_trace(ha: &HAClient, item_id: &str) -> Result<Option<TraceSummary>> {
+    let traces = ha.ws_command(json!({
+        "type": "trace/list",
+        "domain": "automation",
+        "item_id": item_id,
+    })).await?;
+
+    Ok(parse_last_trace(&traces))
+}
+
+pub fn parse_last_trace(traces: &Value) -> Option<TraceSummary> {
+    let text = |v: &Value| v.as_str().filter(|s| !s.is_empty()).map(String::from);
+    let time = |v: &Value| v.as_str()
+        .and_then(|s| DateTime::parse_from_rfc3339(s).ok())
+        .map(|dt| dt.with_timezone(&Utc));
+
+    traces.as_array()?
+        .iter()
+        .filter_map(|t| Some(TraceSummary {
+            start: time(&t["timestamp"]["start"])?,
+            finish: time(&t["timestamp"]["finish"]),
+            execution: text(&t["script_execution"]),
+            trigger: text(&t["trigger"]),
+            last_step: text(&t["last_step"]),
+            error: text(&t["error"]),
+        }))
+        .max_by_key(|t| t.start)
+}
+
+/// Результат выполнения (`script_execution`) на русском.
+pub fn translate_execution(execution: &str) -> &str {
+    match execution {
+        "finished" => "✅ Выполнена",
+        "running" => "⏳ Выполняется",
+        "failed_conditions" => "⛔ Условия не выполнены",
+        "failed_single" => "⛔ Уже запущена (режим single)",
+        "failed_max_runs" => "⛔ Превышен лимит запусков",
+        "aborted" => "⚠️ Прервана",
+        "cancelled" => "⚠️ Отменена",
+        "error" => "❌ Ошибка",
+        other => other,
+    }
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+
+    #[test]
+    fn test_parse_last_trace_picks_latest_run() {
+        let traces = json!([
+            {
+                "timestamp": { "start": "2026-10-18T08:00:00+00:00", "finish": "2026-10-18T08:00:01+00:00" },
+                "script_execution": "finished",
+                "trigger": "state of binary_sensor.door"
+            },
+            {
+                "timestamp": { "start": "2026-10-18T09:00:00+00:00", "finish": null },
+                "script_execution": "error",
+                "last_step": "action/0",
+                "error": "Service not found"
+            }
+        ]);
+
+        let last = parse_last_trace(&traces).expect("trace expected");
+        assert_eq!(last.execution.as_deref(), Some("error"));
+        assert_eq!(last.last_step.as_deref(), Some("action/0"));
+        assert_eq!(last.duration_ms(), None);
+        assert!(parse_last_trace(&json!([])).is_none());
+    }
+}
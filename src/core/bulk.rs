@@ -0,0 +1,155 @@
+use std::sync::Arc;
+
+use anyhow::Result;
+use log::warn;
+
+use crate::bot::router::{BulkAction, DeviceCmd};
+use crate::core::kinds;
+use crate::db;
+use crate::ha::models::Entity;
+use crate::models::AppConfig;
+
+/// Итог группового действия.
+#[derive(Debug, Default)]
+pub struct BulkReport {
+    /// Сколько устройств нужно было переключить (уже находившиеся в целевом состоянии не считаются).
+    pub requested: usize,
+    /// Имена устройств, которые не перешли в целевое состояние.
+    pub failed: Vec<String>,
+}
+
+impl BulkReport {
+    pub fn summary(&self) -> String {
+        match (self.requested, self.failed.is_empty()) {
+            (0, _) => "Все устройства уже в нужном состоянии.".to_string(),
+            (n, true) => format!("✅ Готово: {} из {}.", n, n),
+            (n, false) => format!(
+                "⚠️ Готово: {} из {}. Не ответили: {}.",
+                n - self.failed.len(),
+                n,
+                self.failed.join(", ")
+            ),
+        }
+    }
+}
+
+/// Устройство, попавшее под действие.
+struct Target {
+    entity_id: String,
+    name: String,
+}
+
+impl BulkAction {
+    fn target_state(self) -> &'static str {
+        match self {
+            BulkAction::LightsOff | BulkAction::AllOff => "off",
+            BulkAction::AllOn => "on",
+        }
+    }
+
+    /// Сервис HA для пакетного вызова: `homeassistant.*` работает с несколькими доменами сразу.
+    fn service(self) -> (&'static str, &'static str) {
+        match self {
+            BulkAction::LightsOff => ("light", "turn_off"),
+            BulkAction::AllOff => ("homeassistant", "turn_off"),
+            BulkAction::AllOn => ("homeassistant", "turn_on"),
+        }
+    }
+
+    fn applies_to(self, entity: &Entity) -> bool {
+        let kind = kinds::for_entity(entity);
+        match self {
+            BulkAction::LightsOff => kinds::entity_domain(entity) == "light",
+            BulkAction::AllOff => kind.supports(&DeviceCmd::TurnOff),
+            BulkAction::AllOn => kind.supports(&DeviceCmd::TurnOn),
+        }
+    }
+}
+
+/// Групповое действие над видимыми пользователю устройствами комнаты. Один вызов сервиса на комнату.
+pub async fn run_room(config: &Arc<AppConfig>, user_id: u64, room_id: i64, action: BulkAction) -> Result<BulkReport> {
+    let targets = collect_targets(config, user_id, room_id, action).await?;
+    execute(config, vec![targets], action).await
+}
+
+/// Групповое действие над всеми комнатами (например, «Ухожу из дома»).
+pub async fn run_house(config: &Arc<AppConfig>, user_id: u64, action: BulkAction) -> Result<BulkReport> {
+    let mut batches = Vec::new();
+    for room in db::rooms::get_rooms(&config.db).await? {
+        batches.push(collect_targets(config, user_id, room.id, action).await?);
+    }
+    execute(config, batches, action).await
+}
+
+async fn collect_targets(config: &Arc<AppConfig>, user_id: u64, room_id: i64, action: BulkAction) -> Result<Vec<Target>> {
+    let mut devices = Vec::new();
+    for dev in db::devices::get_devices_by_room(room_id, &config.db).await? {
+        if !db::subscriptions::is_hidden_for(user_id, &dev.entity_id, &config.db).await.unwrap_or(false) {
+            devices.push(dev);
+        }
+    }
+
+    let entity_ids: Vec<String> = devices.iter().map(|d| d.entity_id.clone()).collect();
+    let entities = config.ha_client.fetch_states_by_ids(&entity_ids).await?;
+
+    let targets = devices.into_iter()
+        .filter_map(|dev| {
+            let entity = entities.iter().find(|e| e.entity_id == dev.entity_id)?;
+            if !action.applies_to(entity) || entity.state == action.target_state() {
+                return None;
+            }
+            let name = dev.alias.clone().unwrap_or_else(|| dev.entity_id.clone());
+            Some(Target { entity_id: dev.entity_id, name })
+        })
+        .collect();
+
+    Ok(targets)
+}
+
+async fn execute(config: &Arc<AppConfig>, batches: Vec<Vec<Target>>, action: BulkAction) -> Result<BulkReport> {
+    let batches: Vec<Vec<Target>> = batches.into_iter().filter(|b| !b.is_empty()).collect();
+    let mut report = BulkReport {
+        requested: batches.iter().map(Vec::len).sum(),
+        ..Default::default()
+    };
+    if report.requested == 0 {
+        return Ok(report);
+    }
+
+    let (domain, service) = action.service();
+    let confirmation = config.confirmations.subscribe();
+
+    let mut sent = Vec::new();
+    for batch in &batches {
+        let ids: Vec<&str> = batch.iter().map(|t| t.entity_id.as_str()).collect();
+        let data = serde_json::json!({ "entity_id": ids });
+
+        match config.ha_client.call_service_with_payload(domain, service, data).await {
+            Ok(()) => sent.extend(batch.iter()),
+            Err(e) => {
+                warn!("Bulk {}.{} failed: {}", domain, service, e);
+                report.failed.extend(batch.iter().map(|t| t.name.clone()));
+            }
+        }
+    }
+
+    let sent_ids: Vec<String> = sent.iter().map(|t| t.entity_id.clone()).collect();
+    let silent = config.confirmations.wait_many(confirmation, &sent_ids).await;
+    if silent.is_empty() {
+        return Ok(report);
+    }
+
+    // Событие могло потеряться - сверяем фактическое состояние
+    let silent_ids: Vec<String> = silent.into_iter().collect();
+    let states = config.ha_client.fetch_states_by_ids(&silent_ids).await.unwrap_or_default();
+
+    for target in sent.iter().filter(|t| silent_ids.contains(&t.entity_id)) {
+        let reached = states.iter()
+            .any(|e| e.entity_id == target.entity_id && e.state == action.target_state());
+        if !reached {
+            report.failed.push(target.name.clone());
+        }
+    }
+
+    Ok(report)
+}
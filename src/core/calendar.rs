@@ -0,0 +1,169 @@
+//! Календари и списки дел HA: чтение через сервисы с ответом (`calendar.get_events`, `todo.get_items`).
+
+use anyhow::Result;
+use chrono::{DateTime, Duration, Local, NaiveDate, TimeZone};
+use serde_json::{json, Value};
+
+use crate::ha::HAClient;
+
+/// Начало события: весь день или конкретное время.
+#[derive(Debug, Clone, PartialEq)]
+pub enum EventStart {
+    AllDay(NaiveDate),
+    At(DateTime<Local>),
+}
+
+impl EventStart {
+    fn parse(value: &str) -> Option<Self> {
+        if let Ok(date) = NaiveDate::parse_from_str(value, "%Y-%m-%d") {
+            return Some(Self::AllDay(date));
+        }
+        DateTime::parse_from_rfc3339(value).ok().map(|dt| Self::At(dt.with_timezone(&Local)))
+    }
+
+    pub fn date(&self) -> NaiveDate {
+        match self {
+            Self::AllDay(date) => *date,
+            Self::At(dt) => dt.date_naive(),
+        }
+    }
+
+    /// Время начала для строки списка: "весь день" или "HH:MM".
+    pub fn time_label(&self) -> String {
+        match self {
+            Self::AllDay(_) => "весь день".into(),
+            Self::At(dt) => dt.format("%H:%M").to_string(),
+        }
+    }
+
+    /// Ключ сортировки: события на весь день идут первыми в своем дне.
+    fn sort_key(&self) -> (NaiveDate, Option<DateTime<Local>>) {
+        match self {
+            Self::AllDay(date) => (*date, None),
+            Self::At(dt) => (dt.date_naive(), Some(*dt)),
+        }
+    }
+}
+
+#[derive(Debug, Clone)]
+pub struct CalendarEvent {
+    pub calendar: String,
+    pub start: EventStart,
+    pub summary: String,
+    pub location: Option<String>,
+}
+
+#[derive(Debug, Clone)]
+pub struct TodoItem {
+    pub uid: String,
+    pub summary: String,
+    pub due: Option<String>,
+}
+
+impl TodoItem {
+    /// Короткий идентификатор для callback data (uid может быть длиннее лимита Telegram).
+    pub fn key(&self) -> u32 {
+        crate::core::short_key(&self.uid)
+    }
+}
+
+/// События всех календарей от текущего момента до конца сегодняшнего дня (или на 7 дней вперед).
+pub async fn fetch_upcoming_events(ha: &HAClient, calendars: &[String], week: bool) -> Result<Vec<CalendarEvent>> {
+    if calendars.is_empty() {
+        return Ok(Vec::new());
+    }
+
+    let now = Local::now();
+    let end_date = now.date_naive() + Duration::days(if week { 7 } else { 1 });
+    let end = end_date.and_hms_opt(0, 0, 0)
+        .and_then(|dt| Local.from_local_datetime(&dt).earliest())
+        .unwrap_or(now + Duration::days(1));
+
+    let response = ha.call_service_with_response(
+        "calendar",
+        "get_events",
+        &calendars.join(", "),
+        json!({
+            "start_date_time": now.to_rfc3339(),
+            "end_date_time": end.to_rfc3339(),
+        }),
+    ).await?;
+
+    Ok(parse_events(&response))
+}
+
+pub fn parse_events(response: &Value) -> Vec<CalendarEvent> {
+    let mut events: Vec<CalendarEvent> = response.as_object()
+        .into_iter()
+        .flatten()
+        .flat_map(|(calendar, body)| {
+            body["events"].as_array().into_iter().flatten().filter_map(move |e| {
+                Some(CalendarEvent {
+                    calendar: calendar.clone(),
+                    start: EventStart::parse(e["start"].as_str()?)?,
+                    summary: e["summary"].as_str().unwrap_or("Без названия").to_string(),
+                    location: e["location"].as_str().filter(|l| !l.is_empty()).map(String::from),
+                })
+            })
+        })
+        .collect();
+
+    events.sort_by_key(|e| e.start.sort_key());
+    events
+}
+
+/// Невыполненные пункты списка дел.
+pub async fn fetch_todo_items(ha: &HAClient, entity_id: &str) -> Result<Vec<TodoItem>> {
+    let response = ha.call_service_with_response(
+        "todo",
+        "get_items",
+        entity_id,
+        json!({ "status": ["needs_action"] }),
+    ).await?;
+
+    Ok(parse_todo_items(&response, entity_id))
+}
+
+pub fn parse_todo_items(response: &Value, entity_id: &str) -> Vec<TodoItem> {
+    response[entity_id]["items"].as_array()
+        .into_iter()
+        .flatten()
+        .filter_map(|item| Some(TodoItem {
+            uid: item["uid"].as_str()?.to_string(),
+            summary: item["summary"].as_str().unwrap_or("").to_string(),
+            due: item["due"].as_str().map(String::from),
+        }))
+        .collect()
+}
+
+pub async fn complete_todo_item(ha: &HAClient, entity_id: &str, uid: &str) -> Result<()> {
+    ha.call_service_with_data("todo", "update_item", entity_id, json!({ "item": uid, "status": "completed" })).await
+}
+
+pub async fn add_todo_item(ha: &HAClient, entity_id: &str, summary: &str) -> Result<()> {
+    ha.call_service_with_data("todo", "add_item", entity_id, json!({ "item": summary })).await
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+
+    #[test]
+    fn test_parse_events_sorts_all_day_first() {
+        let response = json!({
+            "calendar.family": { "events": [
+                { "start": "2026-10-18T18:00:00+00:00", "end": "2026-10-18T19:00:00+00:00", "summary": "Ужин" },
+                { "start": "2026-10-18", "end": "2026-10-19", "summary": "День рождения" }
+            ]},
+            "calendar.work": { "events": [
+                { "start": "2026-10-17T09:00:00+00:00", "summary": "Созвон", "location": "" }
+            ]}
+        });
+
+        let events = parse_events(&response);
+        let summaries: Vec<_> = events.iter().map(|e| e.summary.as_str()).collect();
+        assert_eq!(summaries, ["Созвон", "День рождения", "Ужин"]);
+        assert_eq!(events[0].calendar, "calendar.work");
+        assert_eq!(events[0].location, None);
+    }
+}
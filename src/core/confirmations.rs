@@ -0,0 +1,112 @@
+use std::collections::HashSet;
+use std::time::{Duration, Instant};
+
+use dashmap::DashMap;
+use tokio::sync::broadcast;
+
+use crate::ha::HAClient;
+
+/// Сколько действие ждет `state_changed` от целевой сущности.
+pub const CONFIRM_TIMEOUT: Duration = Duration::from_secs(3);
+/// Через сколько маркер ожидания снимается, даже если событие так и не пришло.
+const PENDING_TTL: Duration = Duration::from_secs(60);
+
+/// Подтверждения действий через поток `state_changed`.
+///
+/// Обработчик действия подписывается до вызова сервиса и ждет событие своей сущности.
+/// Если оно не пришло за [`CONFIRM_TIMEOUT`], сущность помечается как ожидающая:
+/// экраны показывают маркер, а процессор событий снимает его и обновляет интерфейс.
+pub struct StateConfirmations {
+    events: broadcast::Sender<String>,
+    pending: DashMap<String, Instant>,
+}
+
+impl Default for StateConfirmations {
+    fn default() -> Self {
+        Self::new()
+    }
+}
+
+impl StateConfirmations {
+    pub fn new() -> Self {
+        let (events, _) = broadcast::channel(64);
+        Self { events, pending: DashMap::new() }
+    }
+
+    /// Подписка на подтверждения. Брать до вызова сервиса, иначе быстрое событие потеряется.
+    pub fn subscribe(&self) -> broadcast::Receiver<String> {
+        self.events.subscribe()
+    }
+
+    /// Ждет событие сущности. `false` - события не было.
+    ///
+    /// На команду, которая ничего не меняет (включить включенное), HA событие не шлет.
+    /// Поэтому после таймаута состояние перечитывается: маркер ожидания ставится, только если
+    /// оно осталось прежним (`previous`) и не совпало с целью действия (`target`).
+    pub async fn wait(
+        &self,
+        mut rx: broadcast::Receiver<String>,
+        ha: &HAClient,
+        entity_id: &str,
+        previous: Option<&str>,
+        target: Option<&str>,
+    ) -> bool {
+        let confirmed = tokio::time::timeout(CONFIRM_TIMEOUT, async {
+            loop {
+                match rx.recv().await {
+                    Ok(id) if id == entity_id => return true,
+                    Ok(_) | Err(broadcast::error::RecvError::Lagged(_)) => continue,
+                    Err(broadcast::error::RecvError::Closed) => return false,
+                }
+            }
+        }).await.unwrap_or(false);
+
+        if confirmed {
+            return true;
+        }
+
+        let current = ha.fetch_states_by_ids(&[entity_id.to_string()]).await.ok()
+            .and_then(|states| states.into_iter().next())
+            .map(|e| e.state);
+
+        let settled = current.is_some_and(|state| {
+            previous.is_some_and(|p| !same_state(p, &state)) || target.is_some_and(|t| same_state(t, &state))
+        });
+        if !settled {
+            self.pending.insert(entity_id.to_string(), Instant::now());
+        }
+        false
+    }
+
+    /// Ждет события сразу нескольких сущностей. Возвращает тех, кто не ответил за таймаут.
+    pub async fn wait_many(&self, mut rx: broadcast::Receiver<String>, entity_ids: &[String]) -> HashSet<String> {
+        let mut waiting: HashSet<String> = entity_ids.iter().cloned().collect();
+
+        let _ = tokio::time::timeout(CONFIRM_TIMEOUT, async {
+            while !waiting.is_empty() {
+                match rx.recv().await {
+                    Ok(id) => { waiting.remove(&id); }
+                    Err(broadcast::error::RecvError::Lagged(_)) => continue,
+                    Err(broadcast::error::RecvError::Closed) => break,
+                }
+            }
+        }).await;
+
+        waiting
+    }
+
+    /// Пришло `state_changed`. Возвращает `true`, если с сущности снят маркер ожидания.
+    pub fn confirm(&self, entity_id: &str) -> bool {
+        let _ = self.events.send(entity_id.to_string());
+        self.pending.remove(entity_id).is_some()
+    }
+
+    pub fn is_pending(&self, entity_id: &str) -> bool {
+        self.pending.get(entity_id).is_some_and(|since| since.elapsed() < PENDING_TTL)
+    }
+}
+
+/// Совпадение состояний с учетом чисел: HA пишет `21.0`, а цель может быть `21`.
+fn same_state(a: &str, b: &str) -> bool {
+    a == b || matches!((a.parse::<f64>(), b.parse::<f64>()), (Ok(x), Ok(y)) if x == y)
+}
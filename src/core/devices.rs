@@ -1,7 +1,7 @@
 use std::sync::Arc;
 use anyhow::{Context, Result};
-use crate::ha::HAClient;
-use crate::ha::models::Entity;
+use crate::bot::router::ControlPayload;
+use crate::core::undo::UndoEntry;
 use crate::models::AppConfig;
 
 #[derive(Debug, Clone)]
@@ -9,6 +9,8 @@ pub enum InputIntent {
     RenameDevice { device_id: i64, room_id: i64 },
     SetStateAlias { device_id: i64, room_id: i64, original_state: String },
     DefineGraphInterval { device_id: i64, room_id: i64 },
+    SetNumericValue { device_id: i64, room_id: i64 },
+    SetText { device_id: i64, room_id: i64 },
 }
 
 #[derive(Debug)]
@@ -29,6 +31,12 @@ pub enum DeviceAction {
     SetTemperature(f32),
     GenerateChart(ChartParams),
     EnterManualInput,
+    SetValue(f64),
+    SelectOption(u16),
+    SetText(String),
+    SetDateTime(i64),
+    /// Только открыть карточку (навигация, черновики), без вызова сервисов.
+    OpenDetail,
 }
 
 #[derive(Debug, Clone, PartialEq)]
@@ -37,152 +45,9 @@ pub struct ChartParams {
     pub offset_hours: i32, // 0 - текущее время, -24 - вчера и т.д.
 }
 
-#[async_trait::async_trait]
-pub trait SmartEntity {
-    fn get_info(&self) -> (&Entity, &str);
-    fn render_button_text(&self, alias: &str) -> String;
-    fn render_button_text_with_state(&self, alias: &str) -> String;
-    // Выполняет логику при нажатии (QuickAction)
-    async fn on_click(&self, ha: &HAClient, action: DeviceAction) -> InteractionResult;
-}
-
-pub enum SmartDevice {
-    Switch(Entity),
-    Light(Entity),
-    Climate(Entity),
-    Sensor(Entity),
-    BinarySensor(Entity),
-    Number(Entity),
-    Unknown(Entity),
-}
-
-impl SmartDevice {
-    pub fn new(entity: Entity) -> Self {
-        let domain = entity.entity_id.split('.').next().unwrap_or("");
-        match domain {
-            "switch" => Self::Switch(entity),
-            "light"  => Self::Light(entity),
-            "climate" => Self::Climate(entity),
-            "sensor" => Self::Sensor(entity),
-            "binary_sensor" => Self::BinarySensor(entity),
-            "number" => Self::Number(entity),
-            _ => Self::Unknown(entity),
-        }
-    }
-}
-
-#[async_trait::async_trait]
-impl SmartEntity for SmartDevice {
-
-    fn get_info(&self) -> (&Entity, &str) {
-        match self {
-            Self::Light(e) => (e, "light"),
-            Self::Switch(e) => (e, "switch"),
-            Self::Climate(e) => (e, "climate"),
-            Self::Sensor(e) => (e, "sensor"),
-            Self::BinarySensor(e) => (e, "binary_sensor"),
-            Self::Number(e) => (e, "number"),
-            Self::Unknown(e) => {
-                let d = e.entity_id.split('.').next().unwrap_or("unknown");
-                (e, d)
-            },
-        }
-    }
-
-    fn render_button_text(&self, alias: &str) -> String {
-        let (entity, domain) = self.get_info();
-        let class = entity.device_class.as_deref().unwrap_or("");
-
-        crate::core::presentation::StateFormatter::format_device_label(
-            alias,
-            domain,
-            class,
-            &entity.state
-        )
-    }
-
-    fn render_button_text_with_state(&self, alias: &str) -> String {
-        let (entity, domain) = self.get_info();
-        let class = entity.device_class.as_deref().unwrap_or("");
-
-        crate::core::presentation::StateFormatter::format_device_label_with_state(
-            alias,
-            domain,
-            class,
-            &entity.state
-        )
-    }
-
-    async fn on_click(&self, ha: &HAClient, action: DeviceAction) -> InteractionResult {
-        let (entity, domain) = self.get_info();
-        let entity_id = &entity.entity_id;
-
-        match self {
-            Self::Light(_) | Self::Switch(_) => {
-                let service = match action {
-                    DeviceAction::Toggle => "toggle",
-                    DeviceAction::TurnOn => "turn_on",
-                    DeviceAction::TurnOff => "turn_off",
-                    DeviceAction::SetLevel(v) => {
-                        let data = serde_json::json!({ "brightness": v });
-                        return if ha.call_service_with_data(domain, "turn_on", entity_id, data).await.is_ok() {
-                            InteractionResult::Processed
-                        } else {
-                            InteractionResult::Error { error: "Failed to set brightness".into() }
-                        };
-                    }
-                    _ => "toggle",
-                };
-
-                if ha.call_service(domain, service, entity_id).await.is_ok() {
-                    InteractionResult::Processed
-                } else {
-                    InteractionResult::Error { error: "HA Service Call Failed".into() }
-                }
-            }
-
-            Self::Climate(_) => {
-                match action {
-                    DeviceAction::SetTemperature(tmp) => {
-                        let data = serde_json::json!({ "temperature": tmp });
-                        if ha.call_service_with_data("climate", "set_temperature", entity_id, data).await.is_ok() {
-                            InteractionResult::RequiresDetail
-                        } else {
-                            InteractionResult::Error { error: "Failed to set temperature".into() }
-                        }
-                    }
-                    DeviceAction::Toggle => {
-                        let _ = ha.call_service("climate", "toggle", entity_id).await;
-                        InteractionResult::Processed
-                    }
-                    _ => InteractionResult::RequiresDetail,
-                }
-            }
-            Self::Sensor(_) | Self::BinarySensor(_) => {
-                match action {
-                    DeviceAction::GenerateChart(_) => InteractionResult::RequiresDetail,
-                    DeviceAction::EnterManualInput => {
-                        InteractionResult::RequiresInput(InputIntent::DefineGraphInterval {
-                            device_id: 0,
-                            room_id: 0
-                        })
-                    }
-                    _ => InteractionResult::RequiresDetail,
-                }
-            }
-
-            Self::Number(_) => InteractionResult::RequiresDetail,
-
-            Self::Unknown(e) => {
-                let _ = ha.call_service(domain, "toggle", &e.entity_id).await;
-                InteractionResult::Processed
-            }
-        }
-    }
-}
-
 pub async fn handle_device_interaction(
     config: &Arc<AppConfig>,
+    user_id: u64,
     device_id: i64,
     action: DeviceAction,
 ) -> Result<InteractionResult> {
@@ -191,17 +56,49 @@ pub async fn handle_device_interaction(
         .context("Device not found in database")?;
 
     let ha_state = config.ha_client
-        .fetch_states_by_ids(&[dev_db.entity_id])
+        .fetch_states_by_ids(std::slice::from_ref(&dev_db.entity_id))
         .await?
         .into_iter()
         .next()
         .context("HA state not found")?;
 
-    let smart_obj = SmartDevice::new(ha_state);
-    let res = smart_obj.on_click(&config.ha_client, action).await;
-    if matches!(res, InteractionResult::Processed) {
-        tokio::time::sleep(std::time::Duration::from_millis(500)).await;
+    // Подписываемся до вызова сервиса, чтобы не пропустить быстрое подтверждение
+    let confirmation = config.confirmations.subscribe();
+
+    let kind = crate::core::kinds::for_entity(&ha_state);
+    let changes_state = !matches!(
+        action,
+        DeviceAction::OpenDetail | DeviceAction::EnterManualInput | DeviceAction::GenerateChart(_)
+    );
+
+    let target = kind.expected_state(&ha_state, &action);
+    let res = kind.on_action(&config.ha_client, &ha_state, action).await;
+    // Сервис вызван успешно - ждем state_changed, в том числе перед перерисовкой карточки
+    let applied = changes_state && matches!(res, InteractionResult::Processed | InteractionResult::RequiresDetail);
+    if applied {
+        config.confirmations.wait(
+            confirmation, &config.ha_client, &dev_db.entity_id, Some(&ha_state.state), target.as_deref()
+        ).await;
+    }
+
+    // Запоминаем прежнее состояние для кнопки «↩ Отменить»
+    let return_to = match res {
+        InteractionResult::Processed => Some(ControlPayload::RoomDetail { room: dev_db.room_id }),
+        InteractionResult::RequiresDetail => Some(ControlPayload::DeviceControl { room: dev_db.room_id, device: device_id }),
+        _ => None,
+    };
+    if let (true, Some(return_to), Some(restore)) = (applied, return_to, kind.restore(&ha_state)) {
+        config.undo.record(user_id, UndoEntry {
+            name: dev_db.alias.clone()
+                .or_else(|| ha_state.friendly_name.clone())
+                .unwrap_or_else(|| dev_db.entity_id.clone()),
+            entity_id: dev_db.entity_id.clone(),
+            restore,
+            state: ha_state.state.clone(),
+            return_to,
+            at: std::time::Instant::now(),
+        });
     }
 
     Ok(res)
-}
\ No newline at end of file
+}
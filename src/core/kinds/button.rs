@@ -0,0 +1,39 @@
+use crate::bot::router::DeviceCmd;
+use crate::core::devices::{DeviceAction, InteractionResult};
+use crate::ha::models::Entity;
+use crate::ha::HAClient;
+
+use super::{entity_domain, service_result, DeviceKind};
+
+pub struct Button;
+
+#[async_trait::async_trait]
+impl DeviceKind for Button {
+    fn domains(&self) -> &'static [&'static str] {
+        &["button", "input_button"]
+    }
+
+    fn icon(&self, _domain: &str, _class: &str, _state: &str) -> &'static str {
+        "🔘"
+    }
+
+    // state у кнопки - отметка времени последнего нажатия, показывать ее бессмысленно
+    fn display_state(&self, _entity: &Entity) -> Option<String> {
+        None
+    }
+
+    fn supports(&self, cmd: &DeviceCmd) -> bool {
+        matches!(cmd, DeviceCmd::Toggle)
+    }
+
+    async fn on_action(&self, ha: &HAClient, entity: &Entity, action: DeviceAction) -> InteractionResult {
+        if action == DeviceAction::OpenDetail {
+            return InteractionResult::RequiresDetail;
+        }
+        service_result(
+            ha.call_service(entity_domain(entity), "press", &entity.entity_id),
+            InteractionResult::Processed,
+            "Failed to press button",
+        ).await
+    }
+}
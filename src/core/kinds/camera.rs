@@ -0,0 +1,40 @@
+use anyhow::Result;
+
+use crate::bot::models::View;
+use crate::bot::router::{DeviceCmd, RenderContext};
+use crate::core::devices::{DeviceAction, InteractionResult};
+use crate::core::types::Device;
+use crate::ha::models::Entity;
+use crate::ha::HAClient;
+
+use super::DeviceKind;
+
+pub struct Camera;
+
+#[async_trait::async_trait]
+impl DeviceKind for Camera {
+    fn domains(&self) -> &'static [&'static str] {
+        &["camera"]
+    }
+
+    fn icon(&self, _domain: &str, _class: &str, _state: &str) -> &'static str {
+        "📷"
+    }
+
+    fn room_cmd(&self) -> Option<DeviceCmd> {
+        None
+    }
+
+    // Снимок показывает карточка, клипы ставит в очередь роутер (video_engine)
+    fn supports(&self, _cmd: &DeviceCmd) -> bool {
+        false
+    }
+
+    async fn on_action(&self, _ha: &HAClient, _entity: &Entity, _action: DeviceAction) -> InteractionResult {
+        InteractionResult::RequiresDetail
+    }
+
+    async fn render_detail(&self, ctx: RenderContext, room_id: i64, dev: Device, entity: Entity, cmd: DeviceCmd) -> Result<View> {
+        crate::bot::screens::control::camera_view::render(ctx, room_id, dev, entity, cmd).await
+    }
+}
@@ -0,0 +1,63 @@
+use crate::bot::router::DeviceCmd;
+use crate::core::devices::{DeviceAction, InteractionResult};
+use crate::ha::models::Entity;
+use crate::ha::HAClient;
+
+use super::{default_state_value, service_result, DeviceKind, RestoreCall};
+
+pub struct Climate;
+
+#[async_trait::async_trait]
+impl DeviceKind for Climate {
+    fn domains(&self) -> &'static [&'static str] {
+        &["climate"]
+    }
+
+    fn icon(&self, _domain: &str, _class: &str, _state: &str) -> &'static str {
+        "🌡"
+    }
+
+    fn format_state(&self, _class: &str, state: &str) -> String {
+        match state.parse::<f64>() {
+            Ok(val) => format!("{:.2}°C", val),
+            Err(_) => default_state_value(state),
+        }
+    }
+
+    fn room_cmd(&self) -> Option<DeviceCmd> {
+        None
+    }
+
+    fn supports(&self, cmd: &DeviceCmd) -> bool {
+        matches!(cmd, DeviceCmd::Toggle | DeviceCmd::SetTemp(_))
+    }
+
+    fn restore(&self, previous: &Entity) -> Option<RestoreCall> {
+        if previous.state == "off" {
+            return Some(RestoreCall::new("climate", "turn_off", serde_json::json!({})));
+        }
+        let temperature = previous.attributes.get("temperature").filter(|v| v.is_number())?;
+        Some(RestoreCall::new("climate", "set_temperature", serde_json::json!({
+            "temperature": temperature,
+            "hvac_mode": previous.state,
+        })))
+    }
+
+    async fn on_action(&self, ha: &HAClient, entity: &Entity, action: DeviceAction) -> InteractionResult {
+        match action {
+            DeviceAction::SetTemperature(tmp) => {
+                let data = serde_json::json!({ "temperature": tmp });
+                service_result(
+                    ha.call_service_with_data("climate", "set_temperature", &entity.entity_id, data),
+                    InteractionResult::RequiresDetail,
+                    "Failed to set temperature",
+                ).await
+            }
+            DeviceAction::Toggle => {
+                let _ = ha.call_service("climate", "toggle", &entity.entity_id).await;
+                InteractionResult::Processed
+            }
+            _ => InteractionResult::RequiresDetail,
+        }
+    }
+}
@@ -0,0 +1,108 @@
+use anyhow::Result;
+use chrono::{NaiveDate, NaiveDateTime, NaiveTime};
+
+use crate::bot::models::View;
+use crate::bot::router::{DeviceCmd, RenderContext};
+use crate::core::devices::{DeviceAction, InteractionResult};
+use crate::core::types::Device;
+use crate::ha::models::Entity;
+use crate::ha::HAClient;
+
+use super::{entity_domain, service_result, DeviceKind, RestoreCall};
+
+/// Какие части даты/времени хранит `input_datetime`.
+#[derive(Debug, Clone, Copy, PartialEq)]
+pub struct DateTimeSpec {
+    pub has_date: bool,
+    pub has_time: bool,
+}
+
+impl DateTimeSpec {
+    pub fn from_entity(entity: &Entity) -> Self {
+        let flag = |key: &str| entity.attributes.get(key).and_then(|v| v.as_bool()).unwrap_or(false);
+        let (has_date, has_time) = (flag("has_date"), flag("has_time"));
+
+        // Хелпер без флагов невозможен в HA, считаем его полным
+        if !has_date && !has_time {
+            return Self { has_date: true, has_time: true };
+        }
+        Self { has_date, has_time }
+    }
+
+    /// Разбирает state (`2024-01-31 07:30:00`, `2024-01-31` или `07:30:00`).
+    pub fn parse_state(&self, state: &str) -> Option<NaiveDateTime> {
+        if let Ok(dt) = NaiveDateTime::parse_from_str(state, "%Y-%m-%d %H:%M:%S") {
+            return Some(dt);
+        }
+        if let Ok(d) = NaiveDate::parse_from_str(state, "%Y-%m-%d") {
+            return d.and_hms_opt(0, 0, 0);
+        }
+        NaiveTime::parse_from_str(state, "%H:%M:%S").ok()
+            .map(|t| NaiveDate::default().and_time(t))
+    }
+
+    pub fn format(&self, value: NaiveDateTime) -> String {
+        match (self.has_date, self.has_time) {
+            (true, true) => value.format("%d.%m.%Y %H:%M").to_string(),
+            (true, false) => value.format("%d.%m.%Y").to_string(),
+            _ => value.format("%H:%M").to_string(),
+        }
+    }
+
+    /// Данные для `input_datetime.set_datetime`.
+    pub fn service_data(&self, value: NaiveDateTime) -> serde_json::Value {
+        match (self.has_date, self.has_time) {
+            (true, true) => serde_json::json!({ "datetime": value.format("%Y-%m-%d %H:%M:%S").to_string() }),
+            (true, false) => serde_json::json!({ "date": value.format("%Y-%m-%d").to_string() }),
+            _ => serde_json::json!({ "time": value.format("%H:%M:%S").to_string() }),
+        }
+    }
+}
+
+pub struct DateTime;
+
+#[async_trait::async_trait]
+impl DeviceKind for DateTime {
+    fn domains(&self) -> &'static [&'static str] {
+        &["input_datetime"]
+    }
+
+    fn icon(&self, _domain: &str, _class: &str, _state: &str) -> &'static str {
+        "📅"
+    }
+
+    fn room_cmd(&self) -> Option<DeviceCmd> {
+        None
+    }
+
+    fn supports(&self, cmd: &DeviceCmd) -> bool {
+        matches!(cmd, DeviceCmd::SetDateTime(_))
+    }
+
+    fn restore(&self, previous: &Entity) -> Option<RestoreCall> {
+        let spec = DateTimeSpec::from_entity(previous);
+        let value = spec.parse_state(&previous.state)?;
+        Some(RestoreCall::new("input_datetime", "set_datetime", spec.service_data(value)))
+    }
+
+    async fn on_action(&self, ha: &HAClient, entity: &Entity, action: DeviceAction) -> InteractionResult {
+        match action {
+            DeviceAction::SetDateTime(ts) => {
+                let Some(value) = chrono::DateTime::from_timestamp(ts, 0).map(|dt| dt.naive_utc()) else {
+                    return InteractionResult::Error { error: "Invalid datetime".into() };
+                };
+                let data = DateTimeSpec::from_entity(entity).service_data(value);
+                service_result(
+                    ha.call_service_with_data(entity_domain(entity), "set_datetime", &entity.entity_id, data),
+                    InteractionResult::RequiresDetail,
+                    "Failed to set datetime",
+                ).await
+            }
+            _ => InteractionResult::RequiresDetail,
+        }
+    }
+
+    async fn render_detail(&self, ctx: RenderContext, room_id: i64, dev: Device, entity: Entity, cmd: DeviceCmd) -> Result<View> {
+        crate::bot::screens::control::datetime_view::render(ctx, room_id, dev, entity, cmd).await
+    }
+}
@@ -0,0 +1,59 @@
+use anyhow::Result;
+
+use crate::bot::models::View;
+use crate::bot::router::{DeviceCmd, RenderContext};
+use crate::core::devices::{DeviceAction, InteractionResult};
+use crate::core::presentation::StateFormatter;
+use crate::core::types::Device;
+use crate::ha::models::Entity;
+use crate::ha::HAClient;
+
+use super::DeviceKind;
+
+/// Тип последнего события event-сущности (`attributes.event_type`).
+pub fn event_type(entity: &Entity) -> Option<&str> {
+    entity.attributes.get("event_type").and_then(|v| v.as_str())
+}
+
+/// События только наблюдаем: карточка с последним событием.
+pub struct Event;
+
+#[async_trait::async_trait]
+impl DeviceKind for Event {
+    fn domains(&self) -> &'static [&'static str] {
+        &["event"]
+    }
+
+    fn icon(&self, _domain: &str, class: &str, _state: &str) -> &'static str {
+        match class {
+            "doorbell" => "🔔",
+            "motion" => "🏃",
+            _ => "🎛",
+        }
+    }
+
+    // Для event-сущностей сюда передается event_type, а не отметка времени из state
+    fn format_state(&self, _class: &str, state: &str) -> String {
+        StateFormatter::translate_event_type(state).to_string()
+    }
+
+    fn display_state(&self, entity: &Entity) -> Option<String> {
+        Some(event_type(entity).unwrap_or("—").to_string())
+    }
+
+    fn room_cmd(&self) -> Option<DeviceCmd> {
+        None
+    }
+
+    fn supports(&self, _cmd: &DeviceCmd) -> bool {
+        false
+    }
+
+    async fn on_action(&self, _ha: &HAClient, _entity: &Entity, _action: DeviceAction) -> InteractionResult {
+        InteractionResult::RequiresDetail
+    }
+
+    async fn render_detail(&self, ctx: RenderContext, room_id: i64, dev: Device, entity: Entity, _cmd: DeviceCmd) -> Result<View> {
+        crate::bot::screens::control::event_view::render(ctx, room_id, dev, entity).await
+    }
+}
@@ -0,0 +1,63 @@
+use crate::bot::router::DeviceCmd;
+use crate::core::devices::{DeviceAction, InteractionResult};
+use crate::ha::models::Entity;
+use crate::ha::HAClient;
+
+use super::{on_off_target, restore_on_off, service_result, DeviceKind, RestoreCall};
+
+pub struct Light;
+
+#[async_trait::async_trait]
+impl DeviceKind for Light {
+    fn domains(&self) -> &'static [&'static str] {
+        &["light"]
+    }
+
+    fn icon(&self, _domain: &str, _class: &str, state: &str) -> &'static str {
+        if state == "on" { "💡" } else { "🌑" }
+    }
+
+    fn supports(&self, cmd: &DeviceCmd) -> bool {
+        matches!(cmd, DeviceCmd::Toggle | DeviceCmd::TurnOn | DeviceCmd::TurnOff | DeviceCmd::SetLevel(_))
+    }
+
+    fn restore(&self, previous: &Entity) -> Option<RestoreCall> {
+        let mut call = restore_on_off(previous)?;
+        if let Some(brightness) = previous.attributes.get("brightness").filter(|v| v.is_number()) {
+            call.data = serde_json::json!({ "brightness": brightness });
+        }
+        Some(call)
+    }
+
+    fn expected_state(&self, _entity: &Entity, action: &DeviceAction) -> Option<String> {
+        match action {
+            DeviceAction::SetLevel(_) => Some("on".into()),
+            _ => on_off_target(action),
+        }
+    }
+
+    async fn on_action(&self, ha: &HAClient, entity: &Entity, action: DeviceAction) -> InteractionResult {
+        let entity_id = &entity.entity_id;
+
+        let service = match action {
+            DeviceAction::TurnOn => "turn_on",
+            DeviceAction::TurnOff => "turn_off",
+            DeviceAction::OpenDetail => return InteractionResult::RequiresDetail,
+            DeviceAction::SetLevel(v) => {
+                let data = serde_json::json!({ "brightness": v });
+                return service_result(
+                    ha.call_service_with_data("light", "turn_on", entity_id, data),
+                    InteractionResult::Processed,
+                    "Failed to set brightness",
+                ).await;
+            }
+            _ => "toggle",
+        };
+
+        service_result(
+            ha.call_service("light", service, entity_id),
+            InteractionResult::Processed,
+            "HA Service Call Failed",
+        ).await
+    }
+}
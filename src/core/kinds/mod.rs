@@ -0,0 +1,191 @@
+//! Реестр типов устройств.
+//!
+//! Каждый домен HA описывается одной реализацией [`DeviceKind`]: иконка и подпись,
+//! быстрое действие из комнаты, карточка, поддерживаемые команды, стиль графика
+//! и фильтр синхронизации. Новый домен добавляется модулем и строкой в [`REGISTRY`].
+
+mod button;
+mod camera;
+mod climate;
+pub mod datetime;
+pub mod event;
+mod light;
+pub mod number;
+pub mod select;
+mod sensor;
+mod switch;
+pub mod text;
+mod unknown;
+
+use anyhow::Result;
+
+use crate::bot::models::View;
+use crate::bot::router::{ControlPayload, DeviceCmd, Payload, RenderContext};
+use crate::charts::ChartStyle;
+use crate::core::devices::{DeviceAction, InteractionResult};
+use crate::core::presentation::StateFormatter;
+use crate::core::types::Device;
+use crate::ha::models::Entity;
+use crate::ha::HAClient;
+
+#[async_trait::async_trait]
+pub trait DeviceKind: Send + Sync {
+    /// Домены HA, которые обслуживает тип. Из них собирается список синхронизации комнат.
+    fn domains(&self) -> &'static [&'static str];
+
+    fn icon(&self, domain: &str, class: &str, state: &str) -> &'static str;
+
+    /// Человекочитаемое значение для кнопок, уведомлений и шапки.
+    fn format_state(&self, _class: &str, state: &str) -> String {
+        default_state_value(state)
+    }
+
+    /// Сырое значение, которое показывается рядом с именем. `None` - только имя.
+    fn display_state(&self, entity: &Entity) -> Option<String> {
+        Some(entity.state.clone())
+    }
+
+    /// Команда кнопки в списке комнаты. `None` - кнопка сразу открывает карточку.
+    fn room_cmd(&self) -> Option<DeviceCmd> {
+        Some(DeviceCmd::Toggle)
+    }
+
+    /// Команды, которые имеют смысл для типа. Остальные только открывают карточку.
+    fn supports(&self, cmd: &DeviceCmd) -> bool;
+
+    fn chart_style(&self) -> Option<ChartStyle> {
+        None
+    }
+
+    /// Вызов сервиса, возвращающий сущность в состояние `previous` (для отмены действия).
+    /// `None` - действие над типом не отменяется.
+    fn restore(&self, _previous: &Entity) -> Option<RestoreCall> {
+        None
+    }
+
+    /// Состояние, в которое действие переводит сущность. Позволяет отличить команду без
+    /// изменений (HA не шлет `state_changed`) от зависшего устройства. `None` - цель неизвестна.
+    fn expected_state(&self, _entity: &Entity, _action: &DeviceAction) -> Option<String> {
+        None
+    }
+
+    /// Фильтр синхронизации отдельных сущностей домена.
+    fn syncs(&self, _entity: &Entity) -> bool {
+        true
+    }
+
+    async fn on_action(&self, ha: &HAClient, entity: &Entity, action: DeviceAction) -> InteractionResult;
+
+    async fn render_detail(
+        &self,
+        ctx: RenderContext,
+        room_id: i64,
+        _dev: Device,
+        _entity: Entity,
+        _cmd: DeviceCmd,
+    ) -> Result<View> {
+        crate::bot::screens::common::in_dev_menu(ctx, Payload::Control(ControlPayload::RoomDetail { room: room_id })).await
+    }
+}
+
+/// Вызов сервиса HA для целевой сущности; `entity_id` добавляется при вызове.
+#[derive(Debug, Clone)]
+pub struct RestoreCall {
+    pub domain: String,
+    pub service: &'static str,
+    pub data: serde_json::Value,
+}
+
+impl RestoreCall {
+    fn new(domain: &str, service: &'static str, data: serde_json::Value) -> Self {
+        Self { domain: domain.to_string(), service, data }
+    }
+}
+
+/// Восстановление on/off-состояния: `turn_on` / `turn_off` своего домена.
+fn restore_on_off(previous: &Entity) -> Option<RestoreCall> {
+    let service = match previous.state.as_str() {
+        "on" => "turn_on",
+        "off" => "turn_off",
+        _ => return None,
+    };
+    Some(RestoreCall::new(entity_domain(previous), service, serde_json::json!({})))
+}
+
+/// Цель явных команд включения и выключения. У переключения цель зависит от скорости HA.
+fn on_off_target(action: &DeviceAction) -> Option<String> {
+    match action {
+        DeviceAction::TurnOn => Some("on".into()),
+        DeviceAction::TurnOff => Some("off".into()),
+        _ => None,
+    }
+}
+
+static REGISTRY: &[&dyn DeviceKind] = &[
+    &light::Light,
+    &switch::Switch,
+    &sensor::Sensor,
+    &sensor::BinarySensor,
+    &number::Number,
+    &climate::Climate,
+    &select::Select,
+    &text::Text,
+    &datetime::DateTime,
+    &camera::Camera,
+    &button::Button,
+    &event::Event,
+];
+
+/// Тип для домена, если он зарегистрирован.
+pub fn find(domain: &str) -> Option<&'static dyn DeviceKind> {
+    REGISTRY.iter().copied().find(|kind| kind.domains().contains(&domain))
+}
+
+/// Тип для домена; незарегистрированные домены обслуживает общий тип (toggle).
+pub fn for_domain(domain: &str) -> &'static dyn DeviceKind {
+    find(domain).unwrap_or(&unknown::Unknown)
+}
+
+pub fn for_entity(entity: &Entity) -> &'static dyn DeviceKind {
+    for_domain(entity_domain(entity))
+}
+
+/// Домены, которые синхронизируются из комнат HA.
+pub fn synced_domains() -> Vec<&'static str> {
+    REGISTRY.iter().flat_map(|kind| kind.domains().iter().copied()).collect()
+}
+
+/// Подпись кнопки устройства: иконка, имя и (опционально) состояние.
+/// `state_alias` - пользовательская подпись текущего состояния, заменяет формат типа.
+pub fn button_label(entity: &Entity, alias: &str, state_alias: Option<&str>, with_state: bool) -> String {
+    let domain = entity_domain(entity);
+    let class = entity.device_class.as_deref().unwrap_or("");
+
+    match (for_entity(entity).display_state(entity), state_alias) {
+        (Some(_), Some(human)) if with_state => {
+            let icon = StateFormatter::get_icon(domain, class, &entity.state);
+            format!("{} {} ({})", icon, alias, human)
+        }
+        (Some(state), None) if with_state => StateFormatter::format_device_label_with_state(alias, domain, class, &state),
+        _ => StateFormatter::format_device_label(alias, domain, class, &entity.state),
+    }
+}
+
+pub(crate) fn entity_domain(entity: &Entity) -> &str {
+    entity.entity_id.split('.').next().unwrap_or("unknown")
+}
+
+fn default_state_value(state: &str) -> String {
+    match state.parse::<f64>() {
+        Ok(val) => format!("{:.2}", val),
+        Err(_) => StateFormatter::translate_state(state).to_string(),
+    }
+}
+
+/// Результат вызова сервиса: `ok` при успехе, иначе ошибка с текстом.
+async fn service_result(call: impl std::future::Future<Output = Result<()>>, ok: InteractionResult, error: &str) -> InteractionResult {
+    match call.await {
+        Ok(()) => ok,
+        Err(_) => InteractionResult::Error { error: error.into() },
+    }
+}
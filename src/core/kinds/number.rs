@@ -0,0 +1,158 @@
+use anyhow::Result;
+
+use crate::bot::models::View;
+use crate::bot::router::{DeviceCmd, RenderContext};
+use crate::core::devices::{DeviceAction, InputIntent, InteractionResult};
+use crate::core::types::Device;
+use crate::ha::models::Entity;
+use crate::ha::HAClient;
+
+use super::{entity_domain, service_result, DeviceKind, RestoreCall};
+
+/// Границы и шаг для `number` / `input_number`, извлеченные из атрибутов HA.
+#[derive(Debug, Clone, Copy, PartialEq)]
+pub struct NumberRange {
+    pub min: f64,
+    pub max: f64,
+    pub step: f64,
+}
+
+impl NumberRange {
+    pub fn from_entity(entity: &Entity) -> Self {
+        let attr = |key: &str, default: f64| {
+            entity.attributes.get(key).and_then(|v| v.as_f64()).unwrap_or(default)
+        };
+
+        let min = attr("min", 0.0);
+        let max = attr("max", 100.0).max(min);
+        let step = attr("step", 1.0);
+        let step = if step > 0.0 { step } else { 1.0 };
+
+        Self { min, max, step }
+    }
+
+    /// Приводит значение к диапазону и сетке шага (от `min`).
+    pub fn snap(&self, value: f64) -> f64 {
+        let steps = ((value - self.min) / self.step).round();
+        let snapped = self.min + steps * self.step;
+        let precision = 10f64.powi(self.decimals() as i32);
+        ((snapped * precision).round() / precision).clamp(self.min, self.max)
+    }
+
+    /// Крупный шаг: примерно десятая часть диапазона, кратная `step`.
+    pub fn coarse_step(&self) -> f64 {
+        let raw = (self.max - self.min) / 10.0;
+        ((raw / self.step).round() * self.step).max(self.step)
+    }
+
+    /// Значение, соответствующее проценту диапазона.
+    pub fn at_percent(&self, percent: u8) -> f64 {
+        self.snap(self.min + (self.max - self.min) * percent as f64 / 100.0)
+    }
+
+    pub fn contains(&self, value: f64) -> bool {
+        value >= self.min && value <= self.max
+    }
+
+    /// Лежит ли значение на сетке шага (с допуском на погрешность дробей).
+    pub fn is_on_step(&self, value: f64) -> bool {
+        (self.snap(value) - value).abs() < self.step * 1e-6
+    }
+
+    /// Количество знаков после запятой, достаточное для отображения шага.
+    pub fn decimals(&self) -> usize {
+        let mut decimals = 0;
+        let mut step = self.step;
+        while decimals < 4 && (step - step.round()).abs() > 1e-9 {
+            step *= 10.0;
+            decimals += 1;
+        }
+        decimals
+    }
+
+    pub fn format(&self, value: f64) -> String {
+        format!("{:.*}", self.decimals(), value)
+    }
+}
+
+pub struct Number;
+
+#[async_trait::async_trait]
+impl DeviceKind for Number {
+    fn domains(&self) -> &'static [&'static str] {
+        &["number", "input_number"]
+    }
+
+    fn icon(&self, _domain: &str, _class: &str, _state: &str) -> &'static str {
+        "🎚"
+    }
+
+    fn room_cmd(&self) -> Option<DeviceCmd> {
+        None
+    }
+
+    fn supports(&self, cmd: &DeviceCmd) -> bool {
+        matches!(cmd, DeviceCmd::SetValue(_) | DeviceCmd::EnterManualInput)
+    }
+
+    fn restore(&self, previous: &Entity) -> Option<RestoreCall> {
+        let value = previous.state.parse::<f64>().ok()?;
+        Some(RestoreCall::new(entity_domain(previous), "set_value", serde_json::json!({ "value": value })))
+    }
+
+    fn expected_state(&self, entity: &Entity, action: &DeviceAction) -> Option<String> {
+        match action {
+            DeviceAction::SetValue(v) => Some(NumberRange::from_entity(entity).snap(*v).to_string()),
+            _ => None,
+        }
+    }
+
+    async fn on_action(&self, ha: &HAClient, entity: &Entity, action: DeviceAction) -> InteractionResult {
+        match action {
+            DeviceAction::SetValue(v) => {
+                // Округляем по шагу: иначе в HA уходят хвосты двоичной дроби вроде 21.299999…
+                let data = serde_json::json!({ "value": NumberRange::from_entity(entity).snap(v) });
+                service_result(
+                    ha.call_service_with_data(entity_domain(entity), "set_value", &entity.entity_id, data),
+                    InteractionResult::RequiresDetail,
+                    "Failed to set value",
+                ).await
+            }
+            DeviceAction::EnterManualInput => {
+                InteractionResult::RequiresInput(InputIntent::SetNumericValue {
+                    device_id: 0,
+                    room_id: 0
+                })
+            }
+            _ => InteractionResult::RequiresDetail,
+        }
+    }
+
+    async fn render_detail(&self, ctx: RenderContext, room_id: i64, dev: Device, entity: Entity, _cmd: DeviceCmd) -> Result<View> {
+        crate::bot::screens::control::number_view::render(ctx, room_id, dev, entity).await
+    }
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+
+    #[test]
+    fn test_number_range_snaps_to_step_and_bounds() {
+        let range = NumberRange { min: 16.0, max: 30.0, step: 0.5 };
+
+        assert_eq!(range.snap(21.3), 21.5);
+        assert_eq!(range.snap(5.0), 16.0);
+        assert_eq!(range.snap(99.0), 30.0);
+        assert_eq!(range.at_percent(50), 23.0);
+        assert_eq!(range.coarse_step(), 1.5);
+        assert_eq!(range.format(21.0), "21.0");
+        assert!(range.is_on_step(21.5));
+        assert!(!range.is_on_step(21.3));
+
+        // Дробный шаг не оставляет хвостов двоичной дроби в отправляемом значении
+        let fine = NumberRange { min: 0.0, max: 30.0, step: 0.1 };
+        assert_eq!(serde_json::json!(fine.snap(21.3f32 as f64)).to_string(), "21.3");
+        assert!(fine.is_on_step(21.3));
+    }
+}
@@ -0,0 +1,73 @@
+use anyhow::Result;
+
+use crate::bot::models::View;
+use crate::bot::router::{DeviceCmd, RenderContext};
+use crate::core::devices::{DeviceAction, InteractionResult};
+use crate::core::types::Device;
+use crate::ha::models::Entity;
+use crate::ha::HAClient;
+
+use super::{entity_domain, service_result, DeviceKind, RestoreCall};
+
+/// Список опций `select` / `input_select`.
+pub fn select_options(entity: &Entity) -> Vec<String> {
+    entity.attributes.get("options")
+        .and_then(|v| v.as_array())
+        .map(|arr| arr.iter().filter_map(|o| o.as_str().map(String::from)).collect())
+        .unwrap_or_default()
+}
+
+pub struct Select;
+
+#[async_trait::async_trait]
+impl DeviceKind for Select {
+    fn domains(&self) -> &'static [&'static str] {
+        &["select", "input_select"]
+    }
+
+    fn icon(&self, _domain: &str, _class: &str, _state: &str) -> &'static str {
+        "📋"
+    }
+
+    fn room_cmd(&self) -> Option<DeviceCmd> {
+        None
+    }
+
+    fn supports(&self, cmd: &DeviceCmd) -> bool {
+        matches!(cmd, DeviceCmd::SelectOption(_))
+    }
+
+    fn restore(&self, previous: &Entity) -> Option<RestoreCall> {
+        select_options(previous).contains(&previous.state).then(|| {
+            RestoreCall::new(entity_domain(previous), "select_option", serde_json::json!({ "option": previous.state }))
+        })
+    }
+
+    fn expected_state(&self, entity: &Entity, action: &DeviceAction) -> Option<String> {
+        match action {
+            DeviceAction::SelectOption(idx) => select_options(entity).into_iter().nth(*idx as usize),
+            _ => None,
+        }
+    }
+
+    async fn on_action(&self, ha: &HAClient, entity: &Entity, action: DeviceAction) -> InteractionResult {
+        match action {
+            DeviceAction::SelectOption(idx) => {
+                let Some(option) = select_options(entity).into_iter().nth(idx as usize) else {
+                    return InteractionResult::Error { error: "Option not found".into() };
+                };
+                let data = serde_json::json!({ "option": option });
+                service_result(
+                    ha.call_service_with_data(entity_domain(entity), "select_option", &entity.entity_id, data),
+                    InteractionResult::RequiresDetail,
+                    "Failed to select option",
+                ).await
+            }
+            _ => InteractionResult::RequiresDetail,
+        }
+    }
+
+    async fn render_detail(&self, ctx: RenderContext, room_id: i64, dev: Device, entity: Entity, cmd: DeviceCmd) -> Result<View> {
+        crate::bot::screens::control::select_view::render(ctx, room_id, dev, entity, cmd).await
+    }
+}
@@ -0,0 +1,113 @@
+use anyhow::Result;
+
+use crate::bot::models::View;
+use crate::bot::router::{DeviceCmd, RenderContext};
+use crate::charts::ChartStyle;
+use crate::core::devices::{DeviceAction, InputIntent, InteractionResult};
+use crate::core::types::Device;
+use crate::ha::models::Entity;
+use crate::ha::HAClient;
+
+use super::{default_state_value, DeviceKind};
+
+pub struct Sensor;
+
+pub struct BinarySensor;
+
+/// Датчики только наблюдаются: ручной ввод задает интервал графика, остальное открывает карточку.
+fn sensor_action(action: DeviceAction) -> InteractionResult {
+    match action {
+        DeviceAction::EnterManualInput => {
+            InteractionResult::RequiresInput(InputIntent::DefineGraphInterval {
+                device_id: 0,
+                room_id: 0
+            })
+        }
+        _ => InteractionResult::RequiresDetail,
+    }
+}
+
+#[async_trait::async_trait]
+impl DeviceKind for Sensor {
+    fn domains(&self) -> &'static [&'static str] {
+        &["sensor"]
+    }
+
+    fn icon(&self, _domain: &str, class: &str, _state: &str) -> &'static str {
+        match class {
+            "temperature" => "🌡",
+            "humidity" => "💧",
+            "battery" => "🔋",
+            "power" => "⚡",
+            _ => "📊",
+        }
+    }
+
+    fn format_state(&self, class: &str, state: &str) -> String {
+        let Ok(val) = state.parse::<f64>() else {
+            return default_state_value(state);
+        };
+        let rounded = format!("{:.2}", val);
+
+        match class {
+            "temperature" => format!("{}°C", rounded),
+            "humidity" => format!("{}%", rounded),
+            "battery" => format!("{}%", rounded),
+            "power" => format!("{} W", rounded),
+            "energy" => format!("{} kWh", rounded),
+            "voltage" => format!("{} V", rounded),
+            _ => rounded,
+        }
+    }
+
+    fn room_cmd(&self) -> Option<DeviceCmd> {
+        None
+    }
+
+    fn supports(&self, cmd: &DeviceCmd) -> bool {
+        matches!(cmd, DeviceCmd::EnterManualInput)
+    }
+
+    fn chart_style(&self) -> Option<ChartStyle> {
+        Some(ChartStyle::Numeric)
+    }
+
+    async fn on_action(&self, _ha: &HAClient, _entity: &Entity, action: DeviceAction) -> InteractionResult {
+        sensor_action(action)
+    }
+
+    async fn render_detail(&self, ctx: RenderContext, room_id: i64, dev: Device, entity: Entity, cmd: DeviceCmd) -> Result<View> {
+        crate::bot::screens::control::sensor_view::render(ctx, room_id, dev, entity, cmd).await
+    }
+}
+
+#[async_trait::async_trait]
+impl DeviceKind for BinarySensor {
+    fn domains(&self) -> &'static [&'static str] {
+        &["binary_sensor"]
+    }
+
+    fn icon(&self, _domain: &str, _class: &str, state: &str) -> &'static str {
+        if state == "on" { "🔔" } else { "🔕" }
+    }
+
+    fn room_cmd(&self) -> Option<DeviceCmd> {
+        None
+    }
+
+    fn supports(&self, cmd: &DeviceCmd) -> bool {
+        matches!(cmd, DeviceCmd::EnterManualInput)
+    }
+
+    fn chart_style(&self) -> Option<ChartStyle> {
+        Some(ChartStyle::Binary)
+    }
+
+    async fn on_action(&self, _ha: &HAClient, _entity: &Entity, action: DeviceAction) -> InteractionResult {
+        sensor_action(action)
+    }
+
+    async fn render_detail(&self, ctx: RenderContext, room_id: i64, dev: Device, entity: Entity, cmd: DeviceCmd) -> Result<View> {
+        crate::bot::screens::control::sensor_view::render(ctx, room_id, dev, entity, cmd).await
+    }
+}
@@ -0,0 +1,51 @@
+use crate::bot::router::DeviceCmd;
+use crate::core::devices::{DeviceAction, InteractionResult};
+use crate::ha::models::Entity;
+use crate::ha::HAClient;
+
+use super::{entity_domain, on_off_target, restore_on_off, service_result, DeviceKind, RestoreCall};
+
+pub struct Switch;
+
+#[async_trait::async_trait]
+impl DeviceKind for Switch {
+    fn domains(&self) -> &'static [&'static str] {
+        &["switch", "input_boolean"]
+    }
+
+    fn icon(&self, domain: &str, _class: &str, state: &str) -> &'static str {
+        match (domain, state) {
+            ("input_boolean", "on") => "✅",
+            ("input_boolean", _) => "⬜",
+            (_, "on") => "🔌",
+            _ => "⚪",
+        }
+    }
+
+    fn supports(&self, cmd: &DeviceCmd) -> bool {
+        matches!(cmd, DeviceCmd::Toggle | DeviceCmd::TurnOn | DeviceCmd::TurnOff)
+    }
+
+    fn restore(&self, previous: &Entity) -> Option<RestoreCall> {
+        restore_on_off(previous)
+    }
+
+    fn expected_state(&self, _entity: &Entity, action: &DeviceAction) -> Option<String> {
+        on_off_target(action)
+    }
+
+    async fn on_action(&self, ha: &HAClient, entity: &Entity, action: DeviceAction) -> InteractionResult {
+        let service = match action {
+            DeviceAction::TurnOn => "turn_on",
+            DeviceAction::TurnOff => "turn_off",
+            DeviceAction::OpenDetail => return InteractionResult::RequiresDetail,
+            _ => "toggle",
+        };
+
+        service_result(
+            ha.call_service(entity_domain(entity), service, &entity.entity_id),
+            InteractionResult::Processed,
+            "HA Service Call Failed",
+        ).await
+    }
+}
@@ -0,0 +1,125 @@
+use anyhow::Result;
+
+use crate::bot::models::View;
+use crate::bot::router::{DeviceCmd, RenderContext};
+use crate::core::devices::{DeviceAction, InputIntent, InteractionResult};
+use crate::core::types::Device;
+use crate::ha::models::Entity;
+use crate::ha::HAClient;
+
+use super::{entity_domain, service_result, DeviceKind, RestoreCall};
+
+/// Ограничения `input_text`: длина и необязательный регулярный шаблон.
+#[derive(Debug, Clone, PartialEq)]
+pub struct TextLimits {
+    pub min: usize,
+    pub max: usize,
+    pub pattern: Option<String>,
+}
+
+impl TextLimits {
+    pub fn from_entity(entity: &Entity) -> Self {
+        let attr = |key: &str, default: usize| {
+            entity.attributes.get(key).and_then(|v| v.as_u64()).map(|v| v as usize).unwrap_or(default)
+        };
+
+        Self {
+            min: attr("min", 0),
+            max: attr("max", 100),
+            pattern: entity.attributes.get("pattern")
+                .and_then(|v| v.as_str())
+                .filter(|p| !p.is_empty())
+                .map(String::from),
+        }
+    }
+
+    /// Проверяет значение так же, как это делает HA. Возвращает текст ошибки для пользователя.
+    pub fn validate(&self, value: &str) -> std::result::Result<(), String> {
+        let len = value.chars().count();
+        if len < self.min || len > self.max {
+            return Err(format!("длина должна быть от {} до {} символов.", self.min, self.max));
+        }
+
+        if let Some(pattern) = &self.pattern {
+            // HA применяет шаблон ко всей строке (fullmatch)
+            let re = regex::Regex::new(&format!("^(?:{})$", pattern))
+                .map_err(|_| "шаблон проверки в HA некорректен.".to_string())?;
+            if !re.is_match(value) {
+                return Err(format!("значение не соответствует шаблону {}.", pattern));
+            }
+        }
+
+        Ok(())
+    }
+}
+
+pub struct Text;
+
+#[async_trait::async_trait]
+impl DeviceKind for Text {
+    fn domains(&self) -> &'static [&'static str] {
+        &["input_text"]
+    }
+
+    fn icon(&self, _domain: &str, _class: &str, _state: &str) -> &'static str {
+        "📝"
+    }
+
+    fn room_cmd(&self) -> Option<DeviceCmd> {
+        None
+    }
+
+    fn supports(&self, cmd: &DeviceCmd) -> bool {
+        matches!(cmd, DeviceCmd::EnterManualInput)
+    }
+
+    fn restore(&self, previous: &Entity) -> Option<RestoreCall> {
+        Some(RestoreCall::new("input_text", "set_value", serde_json::json!({ "value": previous.state })))
+    }
+
+    fn expected_state(&self, _entity: &Entity, action: &DeviceAction) -> Option<String> {
+        match action {
+            DeviceAction::SetText(value) => Some(value.clone()),
+            _ => None,
+        }
+    }
+
+    async fn on_action(&self, ha: &HAClient, entity: &Entity, action: DeviceAction) -> InteractionResult {
+        match action {
+            DeviceAction::SetText(value) => {
+                let data = serde_json::json!({ "value": value });
+                service_result(
+                    ha.call_service_with_data(entity_domain(entity), "set_value", &entity.entity_id, data),
+                    InteractionResult::RequiresDetail,
+                    "Failed to set text",
+                ).await
+            }
+            DeviceAction::EnterManualInput => {
+                InteractionResult::RequiresInput(InputIntent::SetText {
+                    device_id: 0,
+                    room_id: 0
+                })
+            }
+            _ => InteractionResult::RequiresDetail,
+        }
+    }
+
+    async fn render_detail(&self, ctx: RenderContext, room_id: i64, dev: Device, entity: Entity, _cmd: DeviceCmd) -> Result<View> {
+        crate::bot::screens::control::text_view::render(ctx, room_id, dev, entity).await
+    }
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+
+    #[test]
+    fn test_text_limits_validate_length_and_pattern() {
+        let limits = TextLimits { min: 2, max: 5, pattern: Some("[a-z]+".into()) };
+
+        assert!(limits.validate("abc").is_ok());
+        assert!(limits.validate("a").is_err());
+        assert!(limits.validate("abcdef").is_err());
+        assert!(limits.validate("ab1").is_err());
+    }
+}
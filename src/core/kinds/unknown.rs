@@ -0,0 +1,32 @@
+use crate::bot::router::DeviceCmd;
+use crate::core::devices::{DeviceAction, InteractionResult};
+use crate::ha::models::Entity;
+use crate::ha::HAClient;
+
+use super::{entity_domain, DeviceKind};
+
+/// Запасной тип для доменов без собственной реализации: только toggle.
+pub struct Unknown;
+
+#[async_trait::async_trait]
+impl DeviceKind for Unknown {
+    fn domains(&self) -> &'static [&'static str] {
+        &[]
+    }
+
+    fn icon(&self, _domain: &str, _class: &str, _state: &str) -> &'static str {
+        "📦"
+    }
+
+    fn supports(&self, cmd: &DeviceCmd) -> bool {
+        matches!(cmd, DeviceCmd::Toggle)
+    }
+
+    async fn on_action(&self, ha: &HAClient, entity: &Entity, action: DeviceAction) -> InteractionResult {
+        if action == DeviceAction::OpenDetail {
+            return InteractionResult::RequiresDetail;
+        }
+        let _ = ha.call_service(entity_domain(entity), "toggle", &entity.entity_id).await;
+        InteractionResult::Processed
+    }
+}
@@ -0,0 +1,40 @@
+use std::sync::Arc;
+use anyhow::Result;
+
+use crate::db;
+use crate::models::AppConfig;
+
+/// Передает геопозицию пользователя в HA через `device_tracker.see`.
+/// Возвращает `false`, если у пользователя не настроен `dev_id`.
+pub async fn report_location(
+    config: &Arc<AppConfig>,
+    user_id: u64,
+    latitude: f64,
+    longitude: f64,
+    accuracy: Option<f64>,
+) -> Result<bool> {
+    let Some(dev_id) = db::location_trackers::get_dev_id(user_id, &config.db).await? else {
+        return Ok(false);
+    };
+
+    let mut data = serde_json::json!({
+        "dev_id": dev_id,
+        "gps": [latitude, longitude],
+        "source_type": "gps",
+    });
+    if let Some(acc) = accuracy {
+        data["gps_accuracy"] = serde_json::json!(acc.round() as i64);
+    }
+
+    config.ha_client.call_service_with_payload("device_tracker", "see", data).await?;
+    debug!("Location of user {} reported as device_tracker.{}", user_id, dev_id);
+
+    Ok(true)
+}
+
+/// Допустимый `dev_id`: slug HA (латиница в нижнем регистре, цифры, `_`).
+pub fn is_valid_dev_id(dev_id: &str) -> bool {
+    !dev_id.is_empty()
+        && dev_id.len() <= 64
+        && dev_id.chars().all(|c| c.is_ascii_lowercase() || c.is_ascii_digit() || c == '_')
+}
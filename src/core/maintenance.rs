@@ -1,3 +1,4 @@
+use std::collections::HashMap;
 use std::sync::Arc;
 use tokio::time::{interval, Duration, MissedTickBehavior};
 use tokio_util::sync::CancellationToken;
@@ -7,10 +8,14 @@ use log::{info, debug, error};
 
 use crate::models::AppConfig;
 use crate::db;
+use crate::core::kinds;
 use crate::bot::handlers::render_current_view;
 use crate::ha::models::Entity;
 use crate::ha::Room;
 
+/// Реестр зон меняется редко: иконки перечитываются при старте и раз в столько тактов.
+const AREA_ICONS_REFRESH_TICKS: u64 = 40;
+
 pub fn spawn_background_maintenance(
     bot: Bot,
     config: Arc<AppConfig>,
@@ -37,6 +42,9 @@ pub async fn start_background_maintenance(
 
     info!("⚙️ Core: Worker Heartbeat View and Clear alerts started");
 
+    let mut ticks: u64 = 0;
+    let mut area_icons: Option<HashMap<String, String>> = None;
+
     loop {
         tokio::select! {
             _ = interval.tick() => {
@@ -47,7 +55,14 @@ pub async fn start_background_maintenance(
                 }
 
                 refresh_all_active_sessions(&bot, &config).await;
-                refresh_system_data(&config).await;
+                // После неудачной загрузки повторяем на следующем такте
+                if area_icons.is_none() || ticks.is_multiple_of(AREA_ICONS_REFRESH_TICKS) {
+                    area_icons = fetch_area_icons(&config).await.or(area_icons);
+                }
+                ticks += 1;
+
+                refresh_system_data(&config, area_icons.as_ref()).await;
+                crate::core::updates::notify_new_updates(&bot, &config).await;
             }
             _ = cancel_token.cancelled() => {
                 info!("⚙️ Core: Worker was stopped.");
@@ -57,10 +72,10 @@ pub async fn start_background_maintenance(
     }
 }
 
-async fn refresh_system_data(config: &Arc<AppConfig>) {
-    match config.ha_client.fetch_rooms().await {
+async fn refresh_system_data(config: &Arc<AppConfig>, area_icons: Option<&HashMap<String, String>>) {
+    match config.ha_client.fetch_rooms(&kinds::synced_domains()).await {
         Ok(rooms) => {
-            if let Err(e) = refresh_room(&rooms, config).await {
+            if let Err(e) = refresh_room(&rooms, area_icons, config).await {
                 error!("Background sync error: {}", e);
             }
         }
@@ -70,18 +85,51 @@ async fn refresh_system_data(config: &Arc<AppConfig>) {
     }
 }
 
-async fn refresh_room(rooms: &Vec<Room>, config: &Arc<AppConfig>) -> anyhow::Result<()> {
+/// Иконки зон из реестра HA (area_id -> `mdi:*`). Шаблоны Jinja иконку зоны не отдают.
+async fn fetch_area_icons(config: &Arc<AppConfig>) -> Option<HashMap<String, String>> {
+    let areas = match config.ha_client.ws_command(serde_json::json!({ "type": "config/area_registry/list" })).await {
+        Ok(areas) => areas,
+        Err(e) => {
+            error!("Failed to fetch area registry: {}", e);
+            return None;
+        }
+    };
+
+    let icons = areas.as_array()
+        .into_iter()
+        .flatten()
+        .filter_map(|area| {
+            let id = area.get("area_id")?.as_str()?;
+            let icon = area.get("icon")?.as_str()?;
+            Some((id.to_string(), icon.to_string()))
+        })
+        .collect();
+    Some(icons)
+}
+
+async fn refresh_room(
+    rooms: &Vec<Room>,
+    area_icons: Option<&HashMap<String, String>>,
+    config: &Arc<AppConfig>,
+) -> anyhow::Result<()> {
     let mut all_synced_entity_ids = Vec::new();
 
     for room in rooms {
-        match db::rooms::sync_rooms_from_ha(&room.id, &room.name, &config.db).await {
+        let ha_icon = area_icons.and_then(|icons| icons.get(&room.id)).map(String::as_str);
+        match db::rooms::sync_rooms_from_ha(&room.id, &room.name, ha_icon, &config.db).await {
             Ok(_) => {
+                // Тип устройства может исключить отдельные сущности своего домена
+                let entities: Vec<Entity> = room.entities.iter()
+                    .filter(|e| kinds::for_entity(e).syncs(e))
+                    .cloned()
+                    .collect();
+
                 // Собираем все entity_id, которые были синхронизированы
-                for entity in &room.entities {
+                for entity in &entities {
                     all_synced_entity_ids.push(entity.entity_id.clone());
                 }
 
-                if let Err(e) = refresh_entities(&room.id, &room.entities, config).await {
+                if let Err(e) = refresh_entities(&room.id, &entities, config).await {
                     error!("Failed to refresh entities for room {}: {}", room.id, e);
                 }
             }
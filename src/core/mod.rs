@@ -1,14 +1,26 @@
-mod notification;
+pub(crate) mod notification;
 pub(crate) mod maintenance;
 pub(crate) mod presentation;
 pub mod devices;
+pub(crate) mod kinds;
 pub(crate) mod types;
+pub(crate) mod location;
+pub(crate) mod weather;
+pub(crate) mod calendar;
+pub(crate) mod automation;
+pub(crate) mod updates;
+pub(crate) mod persistent_notifications;
+pub(crate) mod confirmations;
+pub(crate) mod bulk;
+pub(crate) mod undo;
+pub(crate) mod search;
 
 use std::sync::Arc;
 use chrono::{DateTime, Utc};
 use serde::{Deserialize, Serialize};
 pub use notification::spawn_notification_processor;
 pub use maintenance::spawn_background_maintenance;
+use crate::core::types::Device;
 use crate::db;
 use crate::models::{AppConfig, UserSession};
 
@@ -50,11 +62,13 @@ impl AppConfig {
 
                     // Г. Форматируем состояние и иконку через ядро
                     let icon = StateFormatter::get_icon(domain, class, &alert.last_state);
-                    let human_state = StateFormatter::format_state_value(domain, class, &alert.last_state);
+                    let human_state = crate::bot::utils::escape_markdown_v2(
+                        &self.format_state(&alert.entity_id, class, &alert.last_state)
+                    );
 
                     // Д. Форматируем мета-информацию (счетчик)
                     let count_suffix = if alert.event_count > 1 {
-                        format!(" [x{}]", alert.event_count)
+                        format!(" \\[x{}\\]", alert.event_count)
                     } else {
                         "".to_string()
                     };
@@ -83,66 +97,211 @@ impl AppConfig {
             });
         }
 
+        // 3. Закрепленные пользователем сущности
+        items.extend(self.get_pinned_header_items(user_id).await);
+
         items
     }
+
+    async fn get_pinned_header_items(&self, user_id: u64) -> Vec<HeaderItem> {
+        use crate::core::presentation::StateFormatter;
+        use crate::bot::utils::escape_markdown_v2;
+
+        let pinned: Vec<String> = match self.sessions.get(&user_id) {
+            Some(session) => session.header_entities.clone(),
+            None => return Vec::new(),
+        };
+        if pinned.is_empty() {
+            return Vec::new();
+        }
+
+        let entities = match self.ha_client.fetch_states_by_ids(&pinned).await {
+            Ok(entities) => entities,
+            Err(e) => {
+                error!("Не удалось получить закрепленные сущности: {}", e);
+                return Vec::new();
+            }
+        };
+
+        entities.iter().map(|entity| {
+            let name = self.name_aliases.get(&entity.entity_id)
+                .map(|r| r.value().clone())
+                .unwrap_or_else(|| weather::display_name(entity));
+
+            match entity.entity_id.split('.').next().unwrap_or("") {
+                "weather" => weather::header_item(entity, name),
+                domain => {
+                    let class = entity.device_class.as_deref().unwrap_or("");
+                    HeaderItem {
+                        icon: StateFormatter::get_icon(domain, class, &entity.state).into(),
+                        label: name,
+                        value: format!("*{}*", escape_markdown_v2(&self.format_state(&entity.entity_id, class, &entity.state))),
+                        last_update: entity.last_changed.unwrap_or_else(Utc::now),
+                    }
+                }
+            }
+        }).collect()
+    }
+
+    /// Закрепляет/открепляет сущность в шапке пользователя. Возвращает новое состояние.
+    pub async fn toggle_header_pin(&self, user_id: u64, entity_id: &str) -> anyhow::Result<bool> {
+        let pinned = db::pinned_headers::toggle_pinned(user_id, entity_id, &self.db).await?;
+
+        if let Some(mut session) = self.sessions.get_mut(&user_id) {
+            if pinned {
+                session.header_entities.push(entity_id.to_string());
+            } else {
+                session.header_entities.retain(|e| e != entity_id);
+            }
+        }
+
+        Ok(pinned)
+    }
+
+    /// Закрепленные сущности пользователя в его порядке.
+    pub fn get_pinned(&self, user_id: u64) -> Vec<String> {
+        self.sessions.get(&user_id)
+            .map(|s| s.header_entities.clone())
+            .unwrap_or_default()
+    }
+
+    /// Сдвигает закрепленную сущность на одну позицию вверх или вниз.
+    pub async fn move_header_pin(&self, user_id: u64, entity_id: &str, up: bool) -> anyhow::Result<()> {
+        let mut order = db::pinned_headers::get_pinned(user_id, &self.db).await?;
+        let Some(index) = order.iter().position(|e| e == entity_id) else { return Ok(()) };
+
+        let target = if up { index.checked_sub(1) } else { Some(index + 1).filter(|&i| i < order.len()) };
+        let Some(target) = target else { return Ok(()) };
+        order.swap(index, target);
+
+        db::pinned_headers::reorder_pinned(user_id, &order, &self.db).await?;
+        if let Some(mut session) = self.sessions.get_mut(&user_id) {
+            session.header_entities = order;
+        }
+        Ok(())
+    }
+
+    /// Пользовательская подпись состояния сущности (например, `on` -> «Открыто»).
+    pub fn state_alias(&self, entity_id: &str, state: &str) -> Option<String> {
+        self.state_aliases.get(entity_id)?.get(state).cloned()
+    }
+
+    /// Состояние для шапки и уведомлений: подпись пользователя, иначе формат типа устройства.
+    pub fn format_state(&self, entity_id: &str, class: &str, state: &str) -> String {
+        let domain = entity_id.split('.').next().unwrap_or("");
+        self.state_alias(entity_id, state)
+            .unwrap_or_else(|| presentation::StateFormatter::format_state_value(domain, class, state))
+    }
+
+    /// Переименовывает устройство в БД и в кэше имен.
+    pub async fn rename_device(&self, device_id: i64, entity_id: &str, name: &str) -> anyhow::Result<()> {
+        db::devices::set_device_alias(device_id, name, &self.db).await?;
+        self.name_aliases.insert(entity_id.to_string(), name.to_string());
+        Ok(())
+    }
+
+    /// Задает (`Some`) или удаляет (`None`) подпись состояния в БД и в кэше.
+    pub async fn set_state_alias(&self, entity_id: &str, original_state: &str, human_state: Option<&str>) -> anyhow::Result<()> {
+        db::set_state_alias(entity_id, original_state, human_state, &self.db).await?;
+
+        match human_state {
+            Some(human) => {
+                self.state_aliases.entry(entity_id.to_string())
+                    .or_default()
+                    .insert(original_state.to_string(), human.to_string());
+            }
+            None => {
+                if let Some(mut states) = self.state_aliases.get_mut(entity_id) {
+                    states.remove(original_state);
+                }
+            }
+        }
+        Ok(())
+    }
+
+    /// Возвращает архивное устройство в список и в кэш имен.
+    pub async fn restore_device(&self, dev: &Device) -> anyhow::Result<()> {
+        db::devices::restore_device(dev.id, &self.db).await?;
+        let name = dev.alias.clone().unwrap_or_else(|| dev.entity_id.clone());
+        self.name_aliases.insert(dev.entity_id.clone(), name);
+        Ok(())
+    }
+
+    /// Удаляет архивное устройство навсегда вместе с его настройками и кэшами.
+    pub async fn purge_device(&self, dev: &Device) -> anyhow::Result<()> {
+        db::devices::purge_device(dev.id, &dev.entity_id, &self.db).await?;
+
+        self.name_aliases.remove(&dev.entity_id);
+        self.state_aliases.remove(&dev.entity_id);
+        for mut session in self.sessions.iter_mut() {
+            session.header_entities.retain(|e| e != &dev.entity_id);
+        }
+        Ok(())
+    }
+
+    /// Переносит настройки архивного устройства на новую сущность (после переименования в HA)
+    /// и обновляет кэши имен, подписей состояний и шапок.
+    pub async fn migrate_device(&self, old: &Device, target: &Device) -> anyhow::Result<()> {
+        db::devices::migrate_device(old, target, &self.db).await?;
+
+        if let Some(name) = old.alias.as_ref().or(target.alias.as_ref()) {
+            self.name_aliases.insert(target.entity_id.clone(), name.clone());
+        }
+        if let Some((_, states)) = self.state_aliases.remove(&old.entity_id) {
+            self.state_aliases.entry(target.entity_id.clone()).or_default().extend(states);
+        }
+        // Закрепление старой сущности занимает ее место в шапке, как и в БД
+        for mut session in self.sessions.iter_mut() {
+            if session.header_entities.contains(&old.entity_id) {
+                session.header_entities.retain(|e| e != &target.entity_id);
+                for e in session.header_entities.iter_mut().filter(|e| **e == old.entity_id) {
+                    *e = target.entity_id.clone();
+                }
+            }
+        }
+        Ok(())
+    }
+
+    /// Получатели админских уведомлений: root и пользователи с флагом is_admin.
+    pub async fn get_admin_ids(&self) -> Vec<i64> {
+        let mut ids = db::get_admin_ids(&self.db).await.unwrap_or_else(|e| {
+            error!("Не удалось получить список админов: {}", e);
+            Vec::new()
+        });
+        ids.push(self.root_user as i64);
+        ids.sort_unstable();
+        ids.dedup();
+        ids
+    }
+
+    pub fn is_header_pinned(&self, user_id: u64, entity_id: &str) -> bool {
+        self.sessions.get(&user_id)
+            .map(|s| s.header_entities.iter().any(|e| e == entity_id))
+            .unwrap_or(false)
+    }
 }
 
-/// TODO realization pinned in future
-    // pub async fn get_header_data(&self, user_id: u64) -> Vec<HeaderItem> {
-
-    // pub async fn get_header_data(&self, user_id: u64) -> Vec<HeaderItem> {
-    //     let mut items = Vec::new();
-
-        // let alerts = crate::db::active_alerts::get_user_alerts(&self.db, user_id).await.context("failed to get user alerts").unwrap();
-        //
-        // if let Some((eid, state, count, last_update_time)) = alerts.into_iter().next() {
-        //     let name = self.name_aliases.get(&eid)
-        //         .map(|s| s.clone())
-        //         .unwrap_or(eid);
-        //
-        //     // Senior Tip: если счетчик больше 1, пользователю полезно это видеть
-        //     let count_suffix = if count > 1 { format!(" (x{})", count) } else { "".to_string() };
-        //
-        //     items.push(HeaderItem {
-        //         icon: "🔔".into(),
-        //         label: "Последнее".into(),
-        //         value: format!("{}: {}{}", name, state, count_suffix),
-        //         last_update: last_update_time,
-        //     });
-        // }
-        //
-        // // --- 2. Персональные закрепленные сенсоры ---
-        // if let Some(session) = self.sessions.get(&user_id) {
-        //     for eid in &session.header_entities {
-        //         let name = self.name_aliases.get(eid)
-        //             .map(|s| s.clone())
-        //             .unwrap_or_else(|| eid.clone());
-        //
-        //         // if let Some(state_lock) = self.global_states.get(eid) {
-        //         //     let snapshot = state_lock.read();
-        //         //     items.push(HeaderItem {
-        //         //         icon: "📍".into(),
-        //         //         label: name,
-        //         //         value: snapshot.current_state.clone(),
-        //         //     });
-        //         // }
-        //     }
-        // }
-
-        // items
-    // }
-// }
+/// Короткий стабильный ключ строкового id для callback data (лимит Telegram - 64 байта).
+/// FNV-1a: стабильный между перезапусками, в отличие от DefaultHasher.
+pub fn short_key(id: &str) -> u32 {
+    id.bytes().fold(0x811c9dc5u32, |hash, b| (hash ^ b as u32).wrapping_mul(0x01000193))
+}
 
 pub async fn update_user_state(config: &Arc<AppConfig>, user_id: u64, msg_id: i32, context: &str) {
     info!("UPDATE USER STATE: user: {}, context: {}", user_id, context);
     let context_owned = context.to_string();
 
+    // Новая сессия - подтягиваем закрепленные сущности из БД
+    let header_entities = match config.sessions.get(&user_id).map(|s| s.header_entities.clone()) {
+        Some(pinned) => pinned,
+        None => db::pinned_headers::get_pinned(user_id, &config.db).await
+            .unwrap_or_default(),
+    };
+
     config.sessions.insert(user_id, UserSession {
         last_menu_id: msg_id,
         current_context: context_owned.clone(),
-        header_entities: config.sessions.get(&user_id)
-            .map(|s| s.header_entities.clone())
-            .unwrap_or_default(),
+        header_entities,
     });
 
 
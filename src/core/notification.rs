@@ -61,48 +61,42 @@ pub fn spawn_notification_processor(
 }
 
 async fn process_and_dispatch(bot: Bot, config: Arc<AppConfig>, event: NotifyEvent) -> anyhow::Result<()> {
+    let confirmed = config.confirmations.confirm(&event.entity_id);
+
     if event.new_state == event.old_state {
+        // Изменились только атрибуты, но на экране висит маркер ожидания - снимаем его
+        if confirmed {
+            let room_id_opt = db::devices::get_room_id_by_entity(&event.entity_id, &config.db).await.unwrap_or(None);
+            refresh_watchers(&bot, &config, &event.entity_id, room_id_opt, &std::collections::HashSet::new());
+        }
         return Ok(());
     }
     info!("Core: New state change {}", event.entity_id, );
 
-    db::device_event_log::EventLogger::record_event(&event.entity_id, &event.new_state, &config.db, ).await?;
+    // У event-сущностей state - отметка времени, смысл несет event_type
+    let domain = event.entity_id.split('.').next().unwrap_or("");
+    let state_value = match (domain, &event.event_type) {
+        ("event", Some(event_type)) => event_type.clone(),
+        _ => event.new_state.clone(),
+    };
+
+    db::device_event_log::EventLogger::record_event(&event.entity_id, &state_value, &config.db, ).await?;
 
     let room_id_opt = db::devices::get_room_id_by_entity(&event.entity_id, &config.db).await.unwrap_or(None);
 
     let recipients = db::subscriptions::get_subscribers(&event.entity_id, &config.db).await.unwrap_or_default();
 
-    let recipients_set: std::collections::HashSet<u64> = recipients.iter().map(|&id| id as u64).collect();
-
-    for entry in config.sessions.iter() {
-        let user_id = *entry.key();
-        let session = entry.value();
-
-        let is_watching = room_id_opt.map_or(false, |rid| is_user_watching_room(session, rid));
+    let recipients_set: std::collections::HashSet<u64> = recipients.iter().map(|&(id, _)| id as u64).collect();
 
-        let is_subscriber = recipients_set.contains(&user_id);
-
-        if is_watching || is_subscriber {
-            let b = bot.clone();
-            let c = config.clone();
-            let mid = MessageId(session.last_menu_id);
-            let ctx_str = session.current_context.clone();
-
-            tokio::spawn(async move {
-                let _ = crate::bot::handlers::render_current_view(
-                    &b, &c, user_id, ChatId(user_id as i64), mid, &ctx_str
-                ).await;
-            });
-        }
-    }
+    refresh_watchers(&bot, &config, &event.entity_id, room_id_opt, &recipients_set);
 
-    // let recipients = db::subscriptions::get_subscribers(&config.db, &event.entity_id).await?;
     if !recipients.is_empty() {
         use crate::core::presentation::StateFormatter;
 
         let room_prefix = if let Some(rid) = room_id_opt {
             if let Ok(Some(room)) = db::rooms::get_room_by_id(rid, &config.db).await {
-                format!("*{}* • ", room.alias.as_deref().unwrap_or(&room.area))
+                // Имена задаются пользователями и уходят в MarkdownV2 - экранируем
+                format!("*{}* • ", crate::bot::utils::escape_markdown_v2(room.alias.as_deref().unwrap_or(&room.area)))
             } else {
                 "".to_string()
             }
@@ -110,30 +104,57 @@ async fn process_and_dispatch(bot: Bot, config: Arc<AppConfig>, event: NotifyEve
             "".to_string()
         };
 
-        // Определяем домен и класс для форматирования
-        let domain = event.entity_id.split('.').next().unwrap_or("");
+        // Определяем класс для форматирования
         let class = event.device_class.as_deref().unwrap_or("");
 
         // Используем наше ядро для красоты
         let icon = StateFormatter::get_icon(domain, class, &event.new_state);
-        let human_state = StateFormatter::format_state_value(domain, class, &event.new_state);
+        // Значение уходит в MarkdownV2: точки в числах и "_" в event_type нужно экранировать
+        let human_state = crate::bot::utils::escape_markdown_v2(
+            &config.format_state(&event.entity_id, class, &state_value)
+        );
 
         let display_name = config.name_aliases.get(&event.entity_id)
             .map(|r| r.value().clone())
             .unwrap_or_else(|| event.friendly_name.clone());
 
-        let message_text = format!("{}{} {}: *{}*", icon, room_prefix, display_name, human_state);
-
-        let data = NotificationData {
-            display_name,
-            human_state: message_text,
-            entity_id: event.entity_id.clone(),
-            recipients,
-        };
+        let message_text = format!(
+            "{}{} {}: *{}*",
+            icon, room_prefix, crate::bot::utils::escape_markdown_v2(&display_name), human_state
+        );
 
+        let is_trigger = is_snapshot_trigger(domain, class, &event.new_state);
+        let entity_id = event.entity_id.clone();
         let b_clone = bot.clone();
         let c_clone = config.clone();
+
+        // Снимки могут грузиться секундами: не держим очередь событий.
+        // Получившим снимок текст не дублируем - фото с той же подписью заменяет его.
         tokio::spawn(async move {
+            let snapshots = if is_trigger {
+                collect_snapshots(&c_clone, &recipients).await
+            } else {
+                Vec::new()
+            };
+
+            let mut photo_recipients = std::collections::HashSet::new();
+            for (user_id, image) in snapshots {
+                photo_recipients.insert(user_id);
+                crate::bot::notification::send_notification_photo_to_recipient(
+                    b_clone.clone(), user_id, message_text.clone(), image
+                ).await;
+            }
+
+            let data = NotificationData {
+                display_name,
+                human_state: message_text,
+                entity_id,
+                recipients: recipients.into_iter()
+                    .map(|(user_id, _)| user_id)
+                    .filter(|user_id| !photo_recipients.contains(user_id))
+                    .collect(),
+            };
+
             if let Err(e) = crate::bot::notification::send_notification(b_clone, c_clone, data).await {
                 error!("Error sending notification: {}", e);
             }
@@ -142,6 +163,89 @@ async fn process_and_dispatch(bot: Bot, config: Arc<AppConfig>, event: NotifyEve
     Ok(())
 }
 
+/// Перерисовывает живые экраны тех, кто смотрит комнату устройства, подписан на него
+/// или держит его в избранном (главный экран и шапка).
+fn refresh_watchers(
+    bot: &Bot,
+    config: &Arc<AppConfig>,
+    entity_id: &str,
+    room_id_opt: Option<i64>,
+    recipients_set: &std::collections::HashSet<u64>,
+) {
+    for entry in config.sessions.iter() {
+        let user_id = *entry.key();
+        let session = entry.value();
+
+        let is_watching = room_id_opt.map_or(false, |rid| is_user_watching_room(session, rid));
+
+        let is_subscriber = recipients_set.contains(&user_id);
+
+        let is_pinned = session.header_entities.iter().any(|e| e == entity_id);
+
+        if is_watching || is_subscriber || is_pinned {
+            let b = bot.clone();
+            let c = config.clone();
+            let mid = MessageId(session.last_menu_id);
+            let ctx_str = session.current_context.clone();
+
+            tokio::spawn(async move {
+                let _ = crate::bot::handlers::render_current_view(
+                    &b, &c, user_id, ChatId(user_id as i64), mid, &ctx_str
+                ).await;
+            });
+        }
+    }
+
+}
+
+/// Можно ли привязать к подписке на сущность снимок с камеры.
+pub fn supports_camera_snapshot(domain: &str, class: &str) -> bool {
+    match domain {
+        "binary_sensor" => matches!(class, "motion" | "occupancy" | "door"),
+        "event" => class == "doorbell",
+        _ => false,
+    }
+}
+
+/// Срабатывание, к которому прикладывается снимок: датчик перешел в `on`,
+/// либо у event-сущности появилось новое событие (state - отметка времени).
+fn is_snapshot_trigger(domain: &str, class: &str, new_state: &str) -> bool {
+    if !supports_camera_snapshot(domain, class) {
+        return false;
+    }
+    match domain {
+        "event" => !matches!(new_state, "unavailable" | "unknown" | ""),
+        _ => new_state == "on",
+    }
+}
+
+/// Снимки с камер, привязанных подписчиками. Каждая камера опрашивается один раз.
+async fn collect_snapshots(config: &Arc<AppConfig>, recipients: &[(i64, Option<String>)]) -> Vec<(i64, Vec<u8>)> {
+    let mut cache: std::collections::HashMap<String, Option<Vec<u8>>> = std::collections::HashMap::new();
+    let mut snapshots = Vec::new();
+
+    for (user_id, camera) in recipients {
+        let Some(camera) = camera else { continue };
+
+        if !cache.contains_key(camera) {
+            let image = match config.ha_client.fetch_camera_snapshot(camera).await {
+                Ok(bytes) => Some(bytes),
+                Err(e) => {
+                    warn!("Core: Snapshot from {} failed: {}", camera, e);
+                    None
+                }
+            };
+            cache.insert(camera.clone(), image);
+        }
+
+        if let Some(Some(image)) = cache.get(camera) {
+            snapshots.push((*user_id, image.clone()));
+        }
+    }
+
+    snapshots
+}
+
 pub async fn refresh_live_interface_for_recipients(
     bot: &Bot,
     config: &Arc<AppConfig>,
@@ -163,10 +267,10 @@ pub async fn refresh_live_interface_for_recipients(
 }
 
 fn is_user_watching_room(session: &UserSession, room_id: i64) -> bool {
-    if let Ok(payload) = serde_json::from_str::<Payload>(&session.current_context) {
-        match payload {
-            Payload::Control(ControlPayload::RoomDetail { room }) => room == room_id,
-            Payload::Control(ControlPayload::DeviceControl { room, .. }) => room == room_id,
+    if let Ok(payload) = Payload::from_string(&session.current_context) {
+        match payload.base() {
+            Payload::Control(ControlPayload::RoomDetail { room }) => *room == room_id,
+            Payload::Control(ControlPayload::DeviceControl { room, .. }) => *room == room_id,
             _ => false,
         }
     } else {
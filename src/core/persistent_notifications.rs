@@ -0,0 +1,101 @@
+//! Постоянные уведомления HA: пересылка админам, список активных и закрытие.
+
+use std::sync::Arc;
+
+use anyhow::Result;
+use log::info;
+use serde_json::json;
+use teloxide::Bot;
+use teloxide::types::{InlineKeyboardButton, InlineKeyboardMarkup};
+use tokio::sync::mpsc;
+use tokio_util::sync::CancellationToken;
+
+use crate::bot::router::{AdminPayload, Payload};
+use crate::ha::{HAClient, PersistentNotification, RepairIssue};
+use crate::models::AppConfig;
+
+/// Длинные сообщения (например, логи интеграций) обрезаем.
+const MESSAGE_MAX_CHARS: usize = 1000;
+
+pub fn spawn_persistent_notification_processor(
+    mut rx: mpsc::Receiver<PersistentNotification>,
+    bot: Bot,
+    config: Arc<AppConfig>,
+    cancel_token: CancellationToken,
+) {
+    info!("Core: Persistent notification processor started");
+
+    tokio::spawn(async move {
+        loop {
+            tokio::select! {
+                Some(notification) = rx.recv() => {
+                    forward_to_admins(&bot, &config, &notification).await;
+                }
+                _ = cancel_token.cancelled() => {
+                    info!("Core: Persistent notification processor shutting down");
+                    break;
+                }
+            }
+        }
+    });
+}
+
+async fn forward_to_admins(bot: &Bot, config: &Arc<AppConfig>, notification: &PersistentNotification) {
+    info!("Core: New HA persistent notification {}", notification.notification_id);
+
+    let kb = InlineKeyboardMarkup::new(vec![vec![
+        InlineKeyboardButton::callback(
+            "✖️ Закрыть в HA",
+            Payload::Admin(AdminPayload::DismissHaNotification {
+                key: crate::core::short_key(&notification.notification_id)
+            }).to_string()
+        ),
+        InlineKeyboardButton::callback(
+            "📋 Все",
+            Payload::Admin(AdminPayload::HaNotifications).to_string()
+        ),
+    ]]);
+
+    crate::bot::notification::send_admin_notification(
+        bot.clone(),
+        config.get_admin_ids().await,
+        format!("🔔 Home Assistant\n\n{}", format_notification(notification, MESSAGE_MAX_CHARS)),
+        kb,
+    ).await;
+}
+
+/// Заголовок и текст уведомления для вывода в Telegram. Текст обрезается до `max_chars`.
+pub fn format_notification(notification: &PersistentNotification, max_chars: usize) -> String {
+    let mut message: String = notification.message.chars().take(max_chars).collect();
+    if notification.message.chars().count() > max_chars {
+        message.push('…');
+    }
+
+    match notification.title.as_deref().filter(|t| !t.is_empty()) {
+        Some(title) => format!("{}\n{}", title, message),
+        None => message,
+    }
+}
+
+/// Активные уведомления, новые сверху.
+pub async fn fetch_active(ha: &HAClient) -> Result<Vec<PersistentNotification>> {
+    let result = ha.ws_command(json!({ "type": "persistent_notification/get" })).await?;
+    let mut notifications: Vec<PersistentNotification> = serde_json::from_value(result)?;
+    notifications.sort_by_key(|n| std::cmp::Reverse(n.created_at));
+    Ok(notifications)
+}
+
+/// Активные (не скрытые пользователем) проблемы из раздела Repairs.
+pub async fn fetch_repairs(ha: &HAClient) -> Result<Vec<RepairIssue>> {
+    let mut result = ha.ws_command(json!({ "type": "repairs/list_issues" })).await?;
+    let issues: Vec<RepairIssue> = serde_json::from_value(result["issues"].take())?;
+    Ok(issues.into_iter().filter(|i| !i.ignored).collect())
+}
+
+pub async fn dismiss(ha: &HAClient, notification_id: &str) -> Result<()> {
+    ha.call_service_with_payload(
+        "persistent_notification",
+        "dismiss",
+        json!({ "notification_id": notification_id }),
+    ).await
+}
@@ -4,8 +4,17 @@ use crate::db::rooms::Room;
 pub struct StateFormatter;
 
 impl Room {
-    /// Возвращает иконку для комнаты, основываясь на её имени или алиасе
-    pub fn icon(&self) -> &'static str {
+    /// Возвращает иконку для комнаты: заданную пользователем, затем иконку зоны HA,
+    /// и только потом подобранную по имени или алиасу
+    pub fn icon(&self) -> &str {
+        if let Some(icon) = self.icon.as_deref() {
+            return icon;
+        }
+
+        if let Some(icon) = self.ha_icon.as_deref().and_then(StateFormatter::map_mdi_room_icon) {
+            return icon;
+        }
+
         // Сначала пробуем взять имя из алиаса, если его нет — из технического area
         let name_for_icon = self.alias.as_deref().unwrap_or(&self.area);
 
@@ -22,26 +31,28 @@ impl Room {
 
 impl StateFormatter {
     /// Возвращает иконку устройства на основе его домена, класса и текущего состояния.
+    /// Устройства описаны в реестре типов, здесь остаются только прочие домены.
     pub fn get_icon(domain: &str, class: &str, state: &str) -> &'static str {
-        match (domain, state) {
-            ("light", "on") => "💡",
-            ("light", _) => "🌑",
-
-            ("switch", "on") => "🔌",
-            ("switch", _) => "⚪",
-
-            ("binary_sensor", "on") => "🔔",
-            ("binary_sensor", _) => "🔕",
-
-            ("climate", _) => "🌡",
+        if let Some(kind) = super::kinds::find(domain) {
+            return kind.icon(domain, class, state);
+        }
 
-            ("sensor", _) => match class {
-                "temperature" => "🌡",
-                "humidity" => "💧",
-                "battery" => "🔋",
-                "power" => "⚡",
-                _ => "📊",
-            },
+        match (domain, state) {
+            ("person" | "device_tracker", "home") => "🏠",
+            ("person" | "device_tracker", "not_home") => "🚶",
+            ("person" | "device_tracker", _) => "📍",
+
+            ("weather", "sunny") => "☀️",
+            ("weather", "clear-night") => "🌙",
+            ("weather", "partlycloudy") => "⛅",
+            ("weather", "cloudy") => "☁️",
+            ("weather", "fog") => "🌫",
+            ("weather", "rainy" | "pouring") => "🌧",
+            ("weather", "lightning" | "lightning-rainy") => "⛈",
+            ("weather", "snowy" | "snowy-rainy" | "hail") => "🌨",
+            ("weather", "windy" | "windy-variant") => "💨",
+            ("weather", "exceptional") => "⚠️",
+            ("weather", _) => "🌤",
 
             ("media_player", "playing") => "▶️",
             ("media_player", "paused") => "⏸",
@@ -65,6 +76,40 @@ impl StateFormatter {
         }
     }
 
+    /// Переводит погодное состояние HA (`condition`) на русский.
+    pub fn translate_weather(condition: &str) -> &str {
+        match condition {
+            "sunny" => "Ясно",
+            "clear-night" => "Ясная ночь",
+            "partlycloudy" => "Переменная облачность",
+            "cloudy" => "Облачно",
+            "fog" => "Туман",
+            "rainy" => "Дождь",
+            "pouring" => "Ливень",
+            "lightning" => "Гроза",
+            "lightning-rainy" => "Гроза с дождем",
+            "snowy" => "Снег",
+            "snowy-rainy" => "Мокрый снег",
+            "hail" => "Град",
+            "windy" | "windy-variant" => "Ветрено",
+            "exceptional" => "Опасная погода",
+            other => Self::translate_state(other),
+        }
+    }
+
+    /// Переводит распространенные `event_type` (пульты, звонки) на русский.
+    pub fn translate_event_type(event_type: &str) -> &str {
+        match event_type {
+            "press" | "single_press" | "initial_press" => "нажатие",
+            "double_press" => "двойное нажатие",
+            "triple_press" => "тройное нажатие",
+            "long_press" => "долгое нажатие",
+            "long_release" | "release" | "short_release" => "отпускание",
+            "ring" => "звонок",
+            other => Self::translate_state(other),
+        }
+    }
+
     /// Финальная сборка всей строки кнопки
     pub fn format_device_label(alias: &str, domain: &str, class: &str, state: &str) -> String {
         let icon = Self::get_icon(domain, class, state);
@@ -72,25 +117,7 @@ impl StateFormatter {
     }
 
     pub fn format_state_value(domain: &str, class: &str, state: &str) -> String {
-        if let Ok(val) = state.parse::<f64>() {
-            let rounded = format!("{:.2}", val);
-
-            return match domain {
-                "climate" => format!("{}°C", rounded),
-                "sensor" => match class {
-                    "temperature" => format!("{}°C", rounded),
-                    "humidity" => format!("{}%", rounded),
-                    "battery" => format!("{}%", rounded),
-                    "power" => format!("{} W", rounded),
-                    "energy" => format!("{} kWh", rounded),
-                    "voltage" => format!("{} V", rounded),
-                    _ => rounded,
-                },
-                _ => rounded,
-            };
-        }
-
-        Self::translate_state(state).to_string()
+        super::kinds::for_domain(domain).format_state(class, state)
     }
 
     /// Собирает итоговую строку для кнопки или уведомления.
@@ -116,6 +143,36 @@ impl StateFormatter {
         }
     }
 
+    /// Эмодзи для иконки зоны HA (`mdi:sofa` и т.п.). `None`, если подходящего нет.
+    pub fn map_mdi_room_icon(mdi: &str) -> Option<&'static str> {
+        let name = mdi.strip_prefix("mdi:")?;
+        let icon = match name {
+            n if n.starts_with("sofa") || n.starts_with("television") => "🛋",
+            n if n.starts_with("bed") => "🛌",
+            n if n.starts_with("silverware") || n.starts_with("stove") || n.starts_with("fridge") || n.starts_with("chef-hat") => "🍳",
+            n if n.starts_with("shower") || n.starts_with("bathtub") => "🛀",
+            n if n.starts_with("toilet") => "🚽",
+            n if n.starts_with("desk") || n.starts_with("monitor") || n.starts_with("laptop") => "🖥",
+            n if n.starts_with("garage") || n.starts_with("car") => "🚗",
+            n if n.starts_with("tree") || n.starts_with("flower") || n.starts_with("grass") => "🌳",
+            n if n.starts_with("teddy-bear") || n.starts_with("baby") || n.starts_with("human-child") => "🧸",
+            n if n.starts_with("washing-machine") => "🧺",
+            n if n.starts_with("hanger") || n.starts_with("wardrobe") => "🧥",
+            n if n.starts_with("door") => "🚪",
+            n if n.starts_with("home") || n.starts_with("house") => "🏠",
+            _ => return None,
+        };
+        Some(icon)
+    }
+
+    /// Пользовательская иконка комнаты: одно эмодзи (допускаются составные, с модификаторами).
+    pub fn is_valid_room_icon(icon: &str) -> bool {
+        let count = icon.chars().count();
+        count > 0
+            && count <= 8
+            && icon.chars().all(|c| !c.is_ascii() && !c.is_alphanumeric() && !c.is_whitespace())
+    }
+
     pub fn get_rooms_header(mode: &super::types::RoomViewMode) -> &'static str {
         match mode {
             super::types::RoomViewMode::Control => "🎮 *Управление*\nВыберите комнату:",
@@ -0,0 +1,159 @@
+use std::sync::Arc;
+
+use anyhow::Result;
+
+use crate::core::types::Device;
+use crate::db;
+use crate::ha::models::Entity;
+use crate::models::AppConfig;
+
+/// Сколько результатов показываем (дальше - уточнять запрос).
+pub const MAX_RESULTS: usize = 40;
+
+/// Найденное устройство вместе с текущим состоянием и комнатой.
+pub struct SearchHit {
+    pub device: Device,
+    pub entity: Entity,
+    pub room_name: String,
+}
+
+/// Поиск видимых пользователю устройств по алиасу, имени из HA и entity_id. Лучшие совпадения первыми.
+/// Состояния из HA запрашиваются только для найденных устройств: поиск идет на каждый
+/// символ inline-запроса.
+pub async fn find_devices(config: &Arc<AppConfig>, user_id: u64, query: &str) -> Result<Vec<SearchHit>> {
+    let tokens = tokenize(query);
+    if tokens.is_empty() {
+        return Ok(Vec::new());
+    }
+
+    let hidden = db::subscriptions::get_hidden_for(user_id, &config.db).await?;
+
+    let mut matches: Vec<(Device, u32)> = db::devices::get_all_devices(&config.db).await?
+        .into_iter()
+        .filter(|dev| !hidden.contains(&dev.entity_id))
+        .filter_map(|dev| {
+            let best = [dev.alias.as_deref(), dev.ha_name.as_deref(), Some(dev.entity_id.as_str())]
+                .into_iter()
+                .flatten()
+                .filter_map(|field| score(&tokens, field))
+                .max()?;
+            Some((dev, best))
+        })
+        .collect();
+
+    matches.sort_by(|a, b| b.1.cmp(&a.1).then_with(|| a.0.id.cmp(&b.0.id)));
+    matches.truncate(MAX_RESULTS);
+    if matches.is_empty() {
+        return Ok(Vec::new());
+    }
+
+    let entity_ids: Vec<String> = matches.iter().map(|(d, _)| d.entity_id.clone()).collect();
+    let entities = config.ha_client.fetch_states_by_ids(&entity_ids).await?;
+    let rooms = db::rooms::get_all_rooms(&config.db).await?;
+
+    let hits = matches.into_iter()
+        .filter_map(|(dev, _)| {
+            let entity = entities.iter().find(|e| e.entity_id == dev.entity_id)?.clone();
+            let room_name = rooms.iter()
+                .find(|r| r.id == dev.room_id)
+                .map(|r| r.alias.clone().unwrap_or_else(|| r.area.clone()))
+                .unwrap_or_default();
+
+            Some(SearchHit { device: dev, entity, room_name })
+        })
+        .collect();
+
+    Ok(hits)
+}
+
+/// Оценка совпадения поля с запросом. Каждое слово запроса должно найтись в поле
+/// (точно, как префикс, как подстрока или с опечаткой), иначе `None`.
+pub fn score(tokens: &[String], field: &str) -> Option<u32> {
+    let words = tokenize(field);
+    tokens.iter()
+        .map(|token| words.iter().filter_map(|word| word_score(token, word)).max())
+        .sum()
+}
+
+fn word_score(token: &str, word: &str) -> Option<u32> {
+    if word == token {
+        Some(4)
+    } else if word.starts_with(token) {
+        Some(3)
+    } else if word.contains(token) {
+        Some(2)
+    } else {
+        // Опечатки: сравниваем и со всем словом, и с его началом той же длины
+        let allowed = match token.chars().count() {
+            0..=3 => return None,
+            4..=6 => 1,
+            _ => 2,
+        };
+        let prefix: String = word.chars().take(token.chars().count()).collect();
+        let distance = levenshtein(token, word).min(levenshtein(token, &prefix));
+        (distance <= allowed).then_some(1)
+    }
+}
+
+/// Слова в нижнем регистре, кириллица транслитерирована: «Свет» и «svet» совпадают.
+fn tokenize(text: &str) -> Vec<String> {
+    let mut normalized = String::with_capacity(text.len());
+    for c in text.chars().flat_map(char::to_lowercase) {
+        match transliterate(c) {
+            Some(latin) => normalized.push_str(latin),
+            None if c.is_alphanumeric() => normalized.push(c),
+            None => normalized.push(' '),
+        }
+    }
+    normalized.split_whitespace().map(String::from).collect()
+}
+
+fn transliterate(c: char) -> Option<&'static str> {
+    let latin = match c {
+        'а' => "a", 'б' => "b", 'в' => "v", 'г' => "g", 'д' => "d",
+        'е' | 'ё' | 'э' => "e", 'ж' => "zh", 'з' => "z", 'и' => "i", 'й' => "y",
+        'к' => "k", 'л' => "l", 'м' => "m", 'н' => "n", 'о' => "o",
+        'п' => "p", 'р' => "r", 'с' => "s", 'т' => "t", 'у' => "u",
+        'ф' => "f", 'х' => "h", 'ц' => "ts", 'ч' => "ch", 'ш' => "sh",
+        'щ' => "sch", 'ъ' | 'ь' => "", 'ы' => "y", 'ю' => "yu", 'я' => "ya",
+        _ => return None,
+    };
+    Some(latin)
+}
+
+fn levenshtein(a: &str, b: &str) -> usize {
+    let b: Vec<char> = b.chars().collect();
+    let mut prev: Vec<usize> = (0..=b.len()).collect();
+
+    for (i, ca) in a.chars().enumerate() {
+        let mut current = vec![i + 1];
+        for (j, cb) in b.iter().enumerate() {
+            let cost = usize::from(ca != *cb);
+            current.push((prev[j] + cost).min(prev[j + 1] + 1).min(current[j] + 1));
+        }
+        prev = current;
+    }
+    prev[b.len()]
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+
+    #[test]
+    fn test_score_matches_across_scripts_and_typos() {
+        let query = |q: &str| tokenize(q);
+
+        // Кириллица против латиницы в обе стороны
+        assert!(score(&query("свет"), "light.svet_kuhnya").is_some());
+        assert!(score(&query("kuhnya"), "Кухня").is_some());
+        // Регистр, префикс и опечатка
+        assert!(score(&query("ЛАМП"), "Лампа у дивана").is_some());
+        assert!(score(&query("ventilator"), "Вентилятор").is_some());
+        assert!(score(&query("дивна"), "Лампа у дивана").is_some());
+        // Все слова запроса обязательны
+        assert!(score(&query("свет спальня"), "Свет на кухне").is_none());
+        // Точное совпадение ценится выше префикса
+        assert!(score(&query("свет"), "Свет") > score(&query("свет"), "Светильник"));
+    }
+}
@@ -13,4 +13,16 @@ pub struct Device {
     pub device_class: String,
     pub device_domain: String,
     pub archived: i64,
-}
\ No newline at end of file
+    /// Section inside the room (e.g. "Потолок"), `None` - without a section.
+    pub group_name: Option<String>,
+    /// Friendly name from Home Assistant as of the last sync, `None` until the first sync.
+    pub ha_name: Option<String>,
+}
+
+#[derive(sqlx::FromRow, Debug)]
+pub struct ArchivedDevice {
+    #[sqlx(flatten)]
+    pub device: Device,
+    /// When the device disappeared from Home Assistant, `None` if archived before this was tracked.
+    pub archived_at: Option<chrono::DateTime<chrono::Utc>>,
+}
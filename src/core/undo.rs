@@ -0,0 +1,80 @@
+use std::sync::Arc;
+use std::time::{Duration, Instant};
+
+use dashmap::DashMap;
+use teloxide::types::InlineKeyboardButton;
+
+use crate::bot::models::View;
+use crate::bot::router::{ControlPayload, Payload, SearchPayload};
+use crate::core::kinds::RestoreCall;
+use crate::models::AppConfig;
+
+/// Сколько после действия доступна отмена.
+pub const UNDO_TTL: Duration = Duration::from_secs(30);
+
+/// Последнее отменяемое действие пользователя.
+#[derive(Debug, Clone)]
+pub struct UndoEntry {
+    pub entity_id: String,
+    pub name: String,
+    pub restore: RestoreCall,
+    /// Состояние до действия - цель отмены.
+    pub state: String,
+    /// Экран, который показывается после отмены.
+    pub return_to: ControlPayload,
+    pub at: Instant,
+}
+
+impl UndoEntry {
+    pub fn is_fresh(&self) -> bool {
+        self.at.elapsed() < UNDO_TTL
+    }
+}
+
+/// Отмена последнего действия, по одной записи на пользователя.
+#[derive(Default)]
+pub struct UndoStore {
+    entries: DashMap<u64, UndoEntry>,
+}
+
+impl UndoStore {
+    pub fn record(&self, user_id: u64, entry: UndoEntry) {
+        self.entries.insert(user_id, entry);
+    }
+
+    /// Забирает запись (отмена одноразовая), даже если срок уже истек.
+    pub fn take(&self, user_id: u64) -> Option<UndoEntry> {
+        self.entries.remove(&user_id).map(|(_, entry)| entry)
+    }
+
+    /// Добавляет кнопку «↩ Отменить» на главный экран, экраны управления и результаты поиска, пока отмена доступна.
+    pub fn attach(&self, user_id: u64, view: &mut View) {
+        let with_undo = matches!(
+            view.payload.base(),
+            Payload::Control(_) | Payload::Home | Payload::Search(SearchPayload::Results)
+        );
+        if !with_undo || view.next_state.is_some() {
+            return;
+        }
+        let Some(entry) = self.entries.get(&user_id) else { return };
+        if !entry.is_fresh() {
+            return;
+        }
+
+        let button = InlineKeyboardButton::callback(format!("↩ Отменить: {}", entry.name), Payload::Undo.to_string());
+        view.kb.inline_keyboard.insert(0, vec![button]);
+    }
+}
+
+/// Выполняет отмену: вызывает сервис восстановления и ждет подтверждения от HA.
+pub async fn apply(config: &Arc<AppConfig>, entry: &UndoEntry) -> anyhow::Result<()> {
+    let confirmation = config.confirmations.subscribe();
+    config.ha_client.call_service_with_data(
+        &entry.restore.domain,
+        entry.restore.service,
+        &entry.entity_id,
+        entry.restore.data.clone(),
+    ).await?;
+    config.confirmations.wait(confirmation, &config.ha_client, &entry.entity_id, None, Some(&entry.state)).await;
+    Ok(())
+}
@@ -0,0 +1,135 @@
+//! Обновления прошивок и аддонов (`update.*`): список, установка и уведомления админам.
+
+use std::sync::Arc;
+
+use log::{error, info};
+use teloxide::prelude::*;
+use teloxide::types::{InlineKeyboardButton, InlineKeyboardMarkup};
+
+use crate::bot::router::{AdminPayload, Payload};
+use crate::ha::models::Entity;
+use crate::models::AppConfig;
+
+/// Бит `UpdateEntityFeature.INSTALL` в `supported_features`.
+const FEATURE_INSTALL: u64 = 1;
+
+fn attr_str<'a>(entity: &'a Entity, key: &str) -> Option<&'a str> {
+    entity.attributes.get(key).and_then(|v| v.as_str()).filter(|s| !s.is_empty())
+}
+
+pub fn installed_version(entity: &Entity) -> &str {
+    attr_str(entity, "installed_version").unwrap_or("—")
+}
+
+pub fn latest_version(entity: &Entity) -> Option<&str> {
+    attr_str(entity, "latest_version")
+}
+
+pub fn title(entity: &Entity) -> String {
+    attr_str(entity, "title")
+        .map(String::from)
+        .or_else(|| entity.friendly_name.clone().filter(|n| !n.is_empty()))
+        .unwrap_or_else(|| entity.entity_id.clone())
+}
+
+pub fn release_summary(entity: &Entity) -> Option<&str> {
+    attr_str(entity, "release_summary")
+}
+
+pub fn release_url(entity: &Entity) -> Option<&str> {
+    attr_str(entity, "release_url")
+}
+
+/// Установка идет: `in_progress` - true или процент выполнения.
+pub fn in_progress(entity: &Entity) -> bool {
+    match entity.attributes.get("in_progress") {
+        Some(serde_json::Value::Bool(b)) => *b,
+        Some(serde_json::Value::Number(_)) => true,
+        _ => false,
+    }
+}
+
+pub fn supports_install(entity: &Entity) -> bool {
+    entity.attributes.get("supported_features")
+        .and_then(|v| v.as_u64())
+        .is_some_and(|f| f & FEATURE_INSTALL != 0)
+}
+
+/// Есть доступное обновление. Пропущенные в HA версии дают состояние `off`.
+pub fn is_pending(entity: &Entity) -> bool {
+    entity.state == "on"
+}
+
+/// Все update-сущности, отсортированные по entity_id.
+pub async fn fetch_updates(config: &AppConfig) -> anyhow::Result<Vec<Entity>> {
+    let mut updates = config.ha_client.fetch_states_by_domain("update").await?;
+    updates.sort_by(|a, b| a.entity_id.cmp(&b.entity_id));
+    Ok(updates)
+}
+
+/// Ключ update-сущности для payload: не зависит от состава списка, в отличие от позиции.
+pub fn key(update: &Entity) -> u32 {
+    crate::core::short_key(&update.entity_id)
+}
+
+pub fn find_by_key(updates: &[Entity], key: u32) -> Option<&Entity> {
+    updates.iter().find(|u| self::key(u) == key)
+}
+
+/// Запускает `update.install` в фоне: установка может идти дольше таймаута HTTP-клиента.
+pub fn spawn_install(config: Arc<AppConfig>, entity_id: String) {
+    tokio::spawn(async move {
+        info!("Updates: install requested for {}", entity_id);
+        if let Err(e) = config.ha_client.call_service("update", "install", &entity_id).await {
+            error!("Updates: install of {} failed: {}", entity_id, e);
+        }
+    });
+}
+
+/// Уведомляет админов о новых версиях. Каждая версия сообщается один раз (дедупликация в БД).
+pub async fn notify_new_updates(bot: &Bot, config: &Arc<AppConfig>) {
+    let updates = match fetch_updates(config).await {
+        Ok(updates) => updates,
+        Err(e) => {
+            error!("Updates: failed to fetch update entities: {}", e);
+            return;
+        }
+    };
+
+    let mut recipients: Option<Vec<i64>> = None;
+
+    for update in &updates {
+        let Some(version) = latest_version(update).filter(|_| is_pending(update)) else { continue };
+
+        match crate::db::update_notifications::mark_notified(&update.entity_id, version, &config.db).await {
+            Ok(true) => {}
+            Ok(false) => continue,
+            Err(e) => {
+                error!("Updates: failed to record notification for {}: {}", update.entity_id, e);
+                continue;
+            }
+        }
+
+        if recipients.is_none() {
+            recipients = Some(config.get_admin_ids().await);
+        }
+
+        let text = format!(
+            "🔄 Доступно обновление\n{}: {} → {}",
+            title(update),
+            installed_version(update),
+            version
+        );
+        let kb = InlineKeyboardMarkup::new(vec![vec![InlineKeyboardButton::callback(
+            "Открыть",
+            Payload::Admin(AdminPayload::Update { key: key(update) }).to_string()
+        )]]);
+
+        crate::bot::notification::send_admin_notification(
+            bot.clone(),
+            recipients.clone().unwrap_or_default(),
+            text,
+            kb,
+        ).await;
+    }
+}
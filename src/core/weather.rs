@@ -0,0 +1,138 @@
+//! Погода: текущие условия и прогноз через `weather.get_forecasts`.
+
+use anyhow::Result;
+use chrono::{DateTime, Utc};
+use serde_json::{json, Value};
+
+use crate::charts::ForecastPoint;
+use crate::core::presentation::StateFormatter;
+use crate::core::HeaderItem;
+use crate::ha::models::Entity;
+use crate::ha::HAClient;
+
+/// Сколько точек прогноза показываем на графике.
+const HOURLY_POINTS: usize = 24;
+const DAILY_POINTS: usize = 7;
+
+/// Запрашивает почасовой или дневной прогноз у HA.
+pub async fn fetch_forecast(ha: &HAClient, entity_id: &str, daily: bool) -> Result<Vec<ForecastPoint>> {
+    let kind = if daily { "daily" } else { "hourly" };
+    let response = ha
+        .call_service_with_response("weather", "get_forecasts", entity_id, json!({ "type": kind }))
+        .await?;
+
+    let limit = if daily { DAILY_POINTS } else { HOURLY_POINTS };
+    let points = parse_forecast(&response, entity_id, limit);
+    if points.is_empty() {
+        return Err(anyhow::anyhow!("Empty {} forecast for {}", kind, entity_id));
+    }
+    Ok(points)
+}
+
+/// Разбирает ответ сервиса: `{ "<entity_id>": { "forecast": [...] } }`.
+pub fn parse_forecast(response: &Value, entity_id: &str, limit: usize) -> Vec<ForecastPoint> {
+    response[entity_id]["forecast"]
+        .as_array()
+        .map(|items| items.iter().filter_map(parse_point).take(limit).collect())
+        .unwrap_or_default()
+}
+
+fn parse_point(item: &Value) -> Option<ForecastPoint> {
+    let time = item["datetime"].as_str()
+        .and_then(|s| DateTime::parse_from_rfc3339(s).ok())?
+        .with_timezone(&Utc);
+
+    Some(ForecastPoint {
+        time,
+        temperature: item["temperature"].as_f64()?,
+        templow: item["templow"].as_f64(),
+        precipitation: item["precipitation"].as_f64().unwrap_or(0.0),
+    })
+}
+
+/// Текущие условия одной строкой: "☀️ Ясно, 21.5 °C".
+pub fn current_summary(entity: &Entity) -> String {
+    let icon = StateFormatter::get_icon("weather", "", &entity.state);
+    let condition = StateFormatter::translate_weather(&entity.state);
+    match temperature(entity) {
+        Some(t) => format!("{} {}, {}", icon, condition, t),
+        None => format!("{} {}", icon, condition),
+    }
+}
+
+/// Подробности: ощущается, влажность, давление, ветер.
+pub fn current_details(entity: &Entity) -> Vec<String> {
+    let attr = |key: &str| entity.attributes.get(key).and_then(|v| v.as_f64());
+    let unit = |key: &str| entity.attributes.get(key).and_then(|v| v.as_str()).unwrap_or("");
+
+    let mut lines = Vec::new();
+    if let Some(v) = attr("apparent_temperature") {
+        lines.push(format!("🤔 Ощущается как {:.1} {}", v, unit("temperature_unit")));
+    }
+    if let Some(v) = attr("humidity") {
+        lines.push(format!("💧 Влажность {:.0}%", v));
+    }
+    if let Some(v) = attr("pressure") {
+        lines.push(format!("🧭 Давление {:.0} {}", v, unit("pressure_unit")));
+    }
+    if let Some(v) = attr("wind_speed") {
+        lines.push(format!("💨 Ветер {:.1} {}", v, unit("wind_speed_unit")));
+    }
+    lines
+}
+
+/// Компактный элемент шапки для закрепленной погоды.
+pub fn header_item(entity: &Entity, label: String) -> HeaderItem {
+    let condition = StateFormatter::translate_weather(&entity.state);
+    let value = match temperature(entity) {
+        Some(t) => format!("*{}* {}", crate::bot::utils::escape_markdown_v2(&t), crate::bot::utils::escape_markdown_v2(condition)),
+        None => crate::bot::utils::escape_markdown_v2(condition),
+    };
+
+    HeaderItem {
+        icon: StateFormatter::get_icon("weather", "", &entity.state).into(),
+        label,
+        value,
+        last_update: entity.last_changed.unwrap_or_else(Utc::now),
+    }
+}
+
+fn temperature(entity: &Entity) -> Option<String> {
+    let value = entity.attributes.get("temperature").and_then(|v| v.as_f64())?;
+    let unit = entity.attributes.get("temperature_unit").and_then(|v| v.as_str()).unwrap_or("°");
+    Some(format!("{:.1} {}", value, unit))
+}
+
+/// Имя погодной сущности для экрана и шапки.
+pub fn display_name(entity: &Entity) -> String {
+    entity.friendly_name.clone()
+        .filter(|n| !n.is_empty())
+        .unwrap_or_else(|| entity.entity_id.clone())
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+
+    #[test]
+    fn test_parse_forecast_limits_and_skips_invalid() {
+        let response = json!({
+            "weather.home": {
+                "forecast": [
+                    { "datetime": "2026-10-18T12:00:00+00:00", "temperature": 12.5, "precipitation": 0.4 },
+                    { "datetime": "broken", "temperature": 11.0 },
+                    { "datetime": "2026-10-18T13:00:00+00:00", "temperature": 11.0, "templow": 5.0 },
+                    { "datetime": "2026-10-18T14:00:00+00:00", "temperature": 10.0 }
+                ]
+            }
+        });
+
+        let points = parse_forecast(&response, "weather.home", 2);
+        assert_eq!(points.len(), 2);
+        assert_eq!(points[0].precipitation, 0.4);
+        assert_eq!(points[1].templow, Some(5.0));
+        assert_eq!(points[1].precipitation, 0.0);
+
+        assert!(parse_forecast(&response, "weather.other", 10).is_empty());
+    }
+}
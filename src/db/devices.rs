@@ -1,5 +1,5 @@
 use sqlx::Row;
-use crate::core::types::Device;
+use crate::core::types::{ArchivedDevice, Device};
 
 /// Synchronizes a device with the database.
 ///
@@ -32,21 +32,25 @@ pub async fn sync_device(
 
     sqlx::query(
         r#"
-        INSERT INTO devices (room_id, entity_id, alias, device_class, device_domain, archived)
+        INSERT INTO devices (room_id, entity_id, alias, ha_name, device_class, device_domain, archived, position)
         VALUES (
             (SELECT id FROM rooms WHERE area = ?1),
             ?2,
             ?3,
+            ?3,
             ?4,
             ?5,
-            0
+            0,
+            (SELECT COALESCE(MAX(position), -1) + 1 FROM devices WHERE room_id = (SELECT id FROM rooms WHERE area = ?1))
         )
         ON CONFLICT(entity_id) DO UPDATE SET
             room_id = (SELECT id FROM rooms WHERE area = ?1),
             device_class = ?4,
             device_domain = ?5,
             alias = COALESCE(alias, ?3),
-            archived = 0
+            ha_name = ?3,
+            archived = 0,
+            archived_at = NULL
         "#
     )
     .bind(ha_area_id)
@@ -57,15 +61,6 @@ pub async fn sync_device(
     .execute(pool)
     .await?;
 
-    // Auto-hide newly discovered devices (user must explicitly unhide them)
-    // Uses INSERT OR IGNORE to only add if device doesn't exist in hidden_entities yet
-    sqlx::query(
-        "INSERT OR IGNORE INTO hidden_entities (entity_id, hide) VALUES (?, 1)"
-    )
-    .bind(ha_entity_id)
-    .execute(pool)
-    .await?;
-
     Ok(())
 }
 
@@ -84,7 +79,7 @@ pub async fn get_devices_by_room(
     pool: &sqlx::SqlitePool,
 ) -> anyhow::Result<Vec<Device>> {
     let rows = sqlx::query(
-        "SELECT id, room_id, entity_id, alias, device_class, device_domain, archived FROM devices WHERE room_id = ? AND archived = 0"
+        "SELECT id, room_id, entity_id, alias, device_class, device_domain, archived, group_name, ha_name FROM devices WHERE room_id = ? AND archived = 0 ORDER BY position, id"
     )
     .bind(room_id)
     .fetch_all(pool)
@@ -100,12 +95,47 @@ pub async fn get_devices_by_room(
             device_class: row.get("device_class"),
             device_domain: row.get("device_domain"),
             archived: row.get("archived"),
+            group_name: row.get("group_name"),
+            ha_name: row.get("ha_name"),
         });
     }
 
     Ok(devices)
 }
 
+/// Retrieves all non-archived devices of a Home Assistant domain.
+///
+/// # Arguments
+///
+/// * `domain` - The domain to filter by (e.g., "camera")
+/// * `pool` - A reference to the SQLite connection pool
+///
+/// # Returns
+///
+/// Returns a `Result<Vec<Device>>` containing all devices of the domain
+pub async fn get_devices_by_domain(
+    domain: &str,
+    pool: &sqlx::SqlitePool,
+) -> sqlx::Result<Vec<Device>> {
+    sqlx::query_as::<_, Device>(
+        "SELECT id, room_id, entity_id, alias, device_class, device_domain, archived, group_name, ha_name FROM devices WHERE device_domain = ? AND archived = 0"
+    )
+    .bind(domain)
+    .fetch_all(pool)
+    .await
+}
+
+/// Retrieves all non-archived devices.
+pub async fn get_all_devices(
+    pool: &sqlx::SqlitePool,
+) -> sqlx::Result<Vec<Device>> {
+    sqlx::query_as::<_, Device>(
+        "SELECT id, room_id, entity_id, alias, device_class, device_domain, archived, group_name, ha_name FROM devices WHERE archived = 0"
+    )
+    .fetch_all(pool)
+    .await
+}
+
 /// Retrieves a device by its ID.
 ///
 /// # Arguments
@@ -121,13 +151,26 @@ pub async fn get_device_by_id(
     pool: &sqlx::SqlitePool,
 ) -> sqlx::Result<Option<Device>> {
     sqlx::query_as::<_, Device>(
-        "SELECT id, room_id, entity_id, alias, device_class, device_domain, archived FROM devices WHERE id = ?"
+        "SELECT id, room_id, entity_id, alias, device_class, device_domain, archived, group_name, ha_name FROM devices WHERE id = ?"
     )
     .bind(id)
     .fetch_optional(pool)
     .await
 }
 
+/// Retrieves a non-archived device by its Home Assistant entity ID.
+pub async fn get_device_by_entity(
+    entity_id: &str,
+    pool: &sqlx::SqlitePool,
+) -> sqlx::Result<Option<Device>> {
+    sqlx::query_as::<_, Device>(
+        "SELECT id, room_id, entity_id, alias, device_class, device_domain, archived, group_name, ha_name FROM devices WHERE entity_id = ? AND archived = 0"
+    )
+    .bind(entity_id)
+    .fetch_optional(pool)
+    .await
+}
+
 /// Retrieves the room ID associated with a device entity.
 ///
 /// # Arguments
@@ -182,6 +225,93 @@ pub async fn get_all_display_names(
     Ok(mapping)
 }
 
+/// Sets a manual alias for a device. Sync never overwrites a non-NULL alias.
+pub async fn set_device_alias(
+    device_id: i64,
+    alias: &str,
+    pool: &sqlx::SqlitePool,
+) -> anyhow::Result<()> {
+    sqlx::query("UPDATE devices SET alias = ? WHERE id = ?")
+        .bind(alias)
+        .bind(device_id)
+        .execute(pool)
+        .await?;
+
+    Ok(())
+}
+
+/// Moves a device one step up or down among the devices of the same group in its room.
+pub async fn move_device(
+    device_id: i64,
+    up: bool,
+    pool: &sqlx::SqlitePool,
+) -> anyhow::Result<()> {
+    let device = get_device_by_id(device_id, pool).await?
+        .ok_or_else(|| anyhow::anyhow!("Device not found"))?;
+    let mut devices = get_devices_by_room(device.room_id, pool).await?;
+
+    let Some(index) = devices.iter().position(|d| d.id == device_id) else {
+        return Ok(());
+    };
+
+    // The neighbour is searched within the same group: groups are rendered as separate sections
+    let same_group = |d: &Device| d.group_name == device.group_name;
+    let target = if up {
+        devices[..index].iter().rposition(same_group)
+    } else {
+        devices[index + 1..].iter().position(same_group).map(|i| index + 1 + i)
+    };
+    let Some(target) = target else {
+        return Ok(());
+    };
+
+    devices.swap(index, target);
+
+    let mut tx = pool.begin().await?;
+    for (position, dev) in devices.iter().enumerate() {
+        sqlx::query("UPDATE devices SET position = ? WHERE id = ?")
+            .bind(position as i64)
+            .bind(dev.id)
+            .execute(&mut *tx)
+            .await?;
+    }
+    tx.commit().await?;
+
+    Ok(())
+}
+
+/// Sets (`Some`) or clears (`None`) the group of a device.
+pub async fn set_device_group(
+    device_id: i64,
+    group_name: Option<&str>,
+    pool: &sqlx::SqlitePool,
+) -> anyhow::Result<()> {
+    sqlx::query("UPDATE devices SET group_name = ? WHERE id = ?")
+        .bind(group_name)
+        .bind(device_id)
+        .execute(pool)
+        .await?;
+
+    Ok(())
+}
+
+/// Distinct group names of a room in display order (by the first device of each group).
+pub async fn get_room_groups(
+    room_id: i64,
+    pool: &sqlx::SqlitePool,
+) -> anyhow::Result<Vec<String>> {
+    let mut groups: Vec<String> = Vec::new();
+    for dev in get_devices_by_room(room_id, pool).await? {
+        if let Some(group) = dev.group_name {
+            if !groups.contains(&group) {
+                groups.push(group);
+            }
+        }
+    }
+
+    Ok(groups)
+}
+
 /// Archives devices that no longer exist in Home Assistant.
 ///
 /// This function should be called after a full sync to mark devices
@@ -211,7 +341,7 @@ pub async fn archive_missing_devices(
         .join(", ");
 
     let query_str = format!(
-        "UPDATE devices SET archived = 1 WHERE entity_id NOT IN ({}) AND archived = 0",
+        "UPDATE devices SET archived = 1, archived_at = CURRENT_TIMESTAMP WHERE entity_id NOT IN ({}) AND archived = 0",
         placeholders
     );
 
@@ -222,4 +352,126 @@ pub async fn archive_missing_devices(
 
     let result = query.execute(pool).await?;
     Ok(result.rows_affected() as usize)
-}
\ No newline at end of file
+}
+
+/// Tables with per-entity settings that follow a device on migration and go away on purge.
+const ENTITY_SETTINGS_TABLES: [&str; 6] = [
+    "subscriptions",
+    "pinned_headers",
+    "hidden_entities",
+    "user_hidden_entities",
+    "state_aliases",
+    "aliases",
+];
+
+/// Retrieves archived devices, the most recently disappeared first.
+pub async fn get_archived_devices(
+    pool: &sqlx::SqlitePool,
+) -> sqlx::Result<Vec<ArchivedDevice>> {
+    sqlx::query_as::<_, ArchivedDevice>(
+        "SELECT id, room_id, entity_id, alias, device_class, device_domain, archived, group_name, ha_name, archived_at FROM devices WHERE archived = 1 ORDER BY archived_at IS NULL, archived_at DESC, id"
+    )
+    .fetch_all(pool)
+    .await
+}
+
+/// Retrieves an archived device by its ID. Returns `None` if the device is gone or active again.
+pub async fn get_archived_device(
+    id: i64,
+    pool: &sqlx::SqlitePool,
+) -> sqlx::Result<Option<ArchivedDevice>> {
+    sqlx::query_as::<_, ArchivedDevice>(
+        "SELECT id, room_id, entity_id, alias, device_class, device_domain, archived, group_name, ha_name, archived_at FROM devices WHERE id = ? AND archived = 1"
+    )
+    .bind(id)
+    .fetch_optional(pool)
+    .await
+}
+
+/// Returns an archived device to the active list. If the entity is still missing
+/// in Home Assistant, the next sync archives it again.
+pub async fn restore_device(
+    device_id: i64,
+    pool: &sqlx::SqlitePool,
+) -> anyhow::Result<()> {
+    sqlx::query("UPDATE devices SET archived = 0, archived_at = NULL WHERE id = ?")
+        .bind(device_id)
+        .execute(pool)
+        .await?;
+
+    Ok(())
+}
+
+/// Permanently deletes an archived device together with its subscriptions, pins,
+/// visibility overrides, state aliases, camera links and event history.
+pub async fn purge_device(
+    device_id: i64,
+    entity_id: &str,
+    pool: &sqlx::SqlitePool,
+) -> anyhow::Result<()> {
+    let mut tx = pool.begin().await?;
+
+    for table in ENTITY_SETTINGS_TABLES.iter().chain(&["device_event_log"]) {
+        sqlx::query(&format!("DELETE FROM {} WHERE entity_id = ?", table))
+            .bind(entity_id)
+            .execute(&mut *tx)
+            .await?;
+    }
+    sqlx::query("UPDATE subscriptions SET camera_entity_id = NULL WHERE camera_entity_id = ?")
+        .bind(entity_id)
+        .execute(&mut *tx)
+        .await?;
+    sqlx::query("DELETE FROM devices WHERE id = ? AND archived = 1")
+        .bind(device_id)
+        .execute(&mut *tx)
+        .await?;
+
+    tx.commit().await?;
+    Ok(())
+}
+
+/// Moves everything configured for an archived device to the entity that replaced it
+/// (e.g. after a rename in Home Assistant), then deletes the archived device.
+///
+/// Alias and group, subscriptions, pins, visibility, state aliases, camera links and
+/// event history are carried over. Settings of the old device win over existing ones
+/// of the target.
+pub async fn migrate_device(
+    old: &Device,
+    target: &Device,
+    pool: &sqlx::SqlitePool,
+) -> anyhow::Result<()> {
+    let mut tx = pool.begin().await?;
+
+    for table in ENTITY_SETTINGS_TABLES {
+        // OR REPLACE: rows of the target that collide with moved rows are dropped
+        sqlx::query(&format!("UPDATE OR REPLACE {} SET entity_id = ? WHERE entity_id = ?", table))
+            .bind(&target.entity_id)
+            .bind(&old.entity_id)
+            .execute(&mut *tx)
+            .await?;
+    }
+    sqlx::query("UPDATE device_event_log SET entity_id = ? WHERE entity_id = ?")
+        .bind(&target.entity_id)
+        .bind(&old.entity_id)
+        .execute(&mut *tx)
+        .await?;
+    sqlx::query("UPDATE subscriptions SET camera_entity_id = ? WHERE camera_entity_id = ?")
+        .bind(&target.entity_id)
+        .bind(&old.entity_id)
+        .execute(&mut *tx)
+        .await?;
+    sqlx::query("UPDATE devices SET alias = COALESCE(?, alias), group_name = COALESCE(?, group_name) WHERE id = ?")
+        .bind(&old.alias)
+        .bind(&old.group_name)
+        .bind(target.id)
+        .execute(&mut *tx)
+        .await?;
+    sqlx::query("DELETE FROM devices WHERE id = ? AND archived = 1")
+        .bind(old.id)
+        .execute(&mut *tx)
+        .await?;
+
+    tx.commit().await?;
+    Ok(())
+}
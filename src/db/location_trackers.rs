@@ -0,0 +1,45 @@
+//! Database module for mapping Telegram users to HA device trackers.
+//!
+//! Location shares from a user are reported to HA under the configured `dev_id`.
+
+use anyhow::Result;
+use sqlx::SqlitePool;
+
+/// Returns the `dev_id` configured for the user, if any.
+pub async fn get_dev_id(user_id: u64, pool: &SqlitePool) -> Result<Option<String>> {
+    let dev_id = sqlx::query_scalar::<_, String>(
+        "SELECT dev_id FROM location_trackers WHERE user_id = ?",
+    )
+    .bind(user_id as i64)
+    .fetch_optional(pool)
+    .await?;
+
+    Ok(dev_id)
+}
+
+/// Creates or replaces the user's `dev_id`.
+pub async fn set_dev_id(user_id: u64, dev_id: &str, pool: &SqlitePool) -> Result<()> {
+    sqlx::query(
+        r#"
+        INSERT INTO location_trackers (user_id, dev_id)
+        VALUES (?, ?)
+        ON CONFLICT(user_id) DO UPDATE SET dev_id = excluded.dev_id
+        "#,
+    )
+    .bind(user_id as i64)
+    .bind(dev_id)
+    .execute(pool)
+    .await?;
+
+    Ok(())
+}
+
+/// Removes the mapping, location shares are ignored afterwards.
+pub async fn clear_dev_id(user_id: u64, pool: &SqlitePool) -> Result<()> {
+    sqlx::query("DELETE FROM location_trackers WHERE user_id = ?")
+        .bind(user_id as i64)
+        .execute(pool)
+        .await?;
+
+    Ok(())
+}
@@ -4,6 +4,9 @@ mod models;
 pub(crate) mod rooms;
 pub(crate) mod devices;
 pub(crate) mod subscriptions;
+pub(crate) mod location_trackers;
+pub(crate) mod pinned_headers;
+pub(crate) mod update_notifications;
 
 use std::collections::HashMap;
 use sqlx::sqlite::{SqliteConnectOptions, SqlitePool};
@@ -99,3 +102,29 @@ pub async fn get_state_aliases(pool: &SqlitePool) -> StateMap {
     }
     map
 }
+
+/// Sets a human-readable label for a raw entity state. `None` removes the label.
+pub async fn set_state_alias(entity_id: &str, original_state: &str, human_state: Option<&str>, pool: &SqlitePool) -> Result<()> {
+    match human_state {
+        Some(human) => {
+            sqlx::query(
+                "INSERT INTO state_aliases (entity_id, original_state, human_state) VALUES (?, ?, ?)
+                 ON CONFLICT(entity_id, original_state) DO UPDATE SET human_state = excluded.human_state"
+            )
+            .bind(entity_id)
+            .bind(original_state)
+            .bind(human)
+            .execute(pool)
+            .await?;
+        }
+        None => {
+            sqlx::query("DELETE FROM state_aliases WHERE entity_id = ? AND original_state = ?")
+                .bind(entity_id)
+                .bind(original_state)
+                .execute(pool)
+                .await?;
+        }
+    }
+
+    Ok(())
+}
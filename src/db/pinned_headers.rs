@@ -0,0 +1,71 @@
+//! Database module for entities pinned by users: the screen header and Home favorites.
+
+use anyhow::Result;
+use sqlx::SqlitePool;
+
+/// Returns the entities pinned by the user, in the user's order.
+pub async fn get_pinned(user_id: u64, pool: &SqlitePool) -> Result<Vec<String>> {
+    let rows = sqlx::query_scalar::<_, String>(
+        "SELECT entity_id FROM pinned_headers WHERE user_id = ? ORDER BY position, entity_id",
+    )
+    .bind(user_id as i64)
+    .fetch_all(pool)
+    .await?;
+
+    Ok(rows)
+}
+
+/// Returns all pins as `(user_id, entity_id)` pairs in each user's order, used to restore sessions on startup.
+pub async fn get_all_pinned(pool: &SqlitePool) -> Result<Vec<(i64, String)>> {
+    let rows = sqlx::query_as::<_, (i64, String)>(
+        "SELECT user_id, entity_id FROM pinned_headers ORDER BY user_id, position, entity_id",
+    )
+    .fetch_all(pool)
+    .await?;
+
+    Ok(rows)
+}
+
+/// Pins the entity if it is not pinned yet (appended to the end), otherwise unpins it.
+/// Returns `true` if the entity is pinned after the call.
+pub async fn toggle_pinned(user_id: u64, entity_id: &str, pool: &SqlitePool) -> Result<bool> {
+    let removed = sqlx::query("DELETE FROM pinned_headers WHERE user_id = ? AND entity_id = ?")
+        .bind(user_id as i64)
+        .bind(entity_id)
+        .execute(pool)
+        .await?
+        .rows_affected();
+
+    if removed > 0 {
+        return Ok(false);
+    }
+
+    sqlx::query(
+        "INSERT INTO pinned_headers (user_id, entity_id, position)
+         VALUES (?, ?, (SELECT COALESCE(MAX(position), -1) + 1 FROM pinned_headers WHERE user_id = ?))",
+    )
+    .bind(user_id as i64)
+    .bind(entity_id)
+    .bind(user_id as i64)
+    .execute(pool)
+    .await?;
+
+    Ok(true)
+}
+
+/// Rewrites the positions of the user's pins to match `order`.
+pub async fn reorder_pinned(user_id: u64, order: &[String], pool: &SqlitePool) -> Result<()> {
+    let mut tx = pool.begin().await?;
+
+    for (position, entity_id) in order.iter().enumerate() {
+        sqlx::query("UPDATE pinned_headers SET position = ? WHERE user_id = ? AND entity_id = ?")
+            .bind(position as i64)
+            .bind(user_id as i64)
+            .bind(entity_id)
+            .execute(&mut *tx)
+            .await?;
+    }
+
+    tx.commit().await?;
+    Ok(())
+}
@@ -8,30 +8,41 @@ pub struct Room {
     pub area: String,
     pub alias: Option<String>,
     pub hide: bool,
+    /// Custom emoji set by the user.
+    pub icon: Option<String>,
+    /// Icon of the HA area (e.g. `mdi:sofa`), used as the default.
+    pub ha_icon: Option<String>,
+    /// Render sensors two per row in the control view.
+    pub compact_sensors: bool,
 }
 
+const ROOM_COLUMNS: &str = "id, area, alias, hide, icon, ha_icon, compact_sensors";
+
 /// Synchronize rooms from Home Assistant.
 ///
-/// Inserts a new room or updates an existing room's alias if it's currently NULL.
-/// The `hide` field is not modified during updates.
+/// Inserts a new room (at the end of the list) or updates an existing room's alias if it's currently NULL.
+/// The `hide` and `position` fields are not modified during updates. `ha_icon` is kept if `None` is passed.
 pub async fn sync_rooms_from_ha(
     entity_id: &str,
     default_name: &str,
+    ha_icon: Option<&str>,
     pool: &SqlitePool,
 ) -> Result<()> {
     sqlx::query(
         r#"
-        INSERT INTO rooms (area, alias, hide)
-        VALUES (?1, ?2, 0)
+        INSERT INTO rooms (area, alias, hide, position, ha_icon)
+        VALUES (?1, ?2, 0, (SELECT COALESCE(MAX(position), -1) + 1 FROM rooms), ?3)
         ON CONFLICT(area) DO UPDATE SET
             -- If room already exists, we don't touch 'hide'.
             -- We can only update the technical name in alias,
             -- BUT only if it's currently NULL.
-            alias = COALESCE(alias, ?2)
+            alias = COALESCE(alias, ?2),
+            ha_icon = COALESCE(?3, ha_icon)
         "#
     )
     .bind(entity_id)
     .bind(default_name)
+    .bind(ha_icon)
     .execute(pool)
     .await
     .map_err(|e| anyhow::anyhow!("Failed to sync rooms from HA: {}", e))?;
@@ -67,10 +78,22 @@ pub async fn upsert_room(
     Ok(())
 }
 
-/// Get all non-hidden rooms.
+/// Get all non-hidden rooms in the user-defined order.
 pub async fn get_rooms(pool: &SqlitePool) -> Result<Vec<Room>> {
     let rooms = sqlx::query_as::<_, Room>(
-        "SELECT id, area, alias, hide FROM rooms WHERE hide = 0"
+        &format!("SELECT {} FROM rooms WHERE hide = 0 ORDER BY position, id", ROOM_COLUMNS)
+    )
+    .fetch_all(pool)
+    .await
+    .map_err(|e| anyhow::anyhow!("Failed to fetch rooms: {}", e))?;
+
+    Ok(rooms)
+}
+
+/// Get all rooms including hidden ones (for settings), in the user-defined order.
+pub async fn get_all_rooms(pool: &SqlitePool) -> Result<Vec<Room>> {
+    let rooms = sqlx::query_as::<_, Room>(
+        &format!("SELECT {} FROM rooms ORDER BY position, id", ROOM_COLUMNS)
     )
     .fetch_all(pool)
     .await
@@ -82,7 +105,7 @@ pub async fn get_rooms(pool: &SqlitePool) -> Result<Vec<Room>> {
 /// Get a room by its ID.
 pub async fn get_room_by_id(id: i64, pool: &SqlitePool) -> Result<Option<Room>> {
     let row = sqlx::query(
-        "SELECT id, area, alias, hide FROM rooms WHERE id = ?"
+        &format!("SELECT {} FROM rooms WHERE id = ?", ROOM_COLUMNS)
     )
     .bind(id)
     .fetch_optional(pool)
@@ -94,9 +117,68 @@ pub async fn get_room_by_id(id: i64, pool: &SqlitePool) -> Result<Option<Room>>
         area: row.get("area"),
         alias: row.get("alias"), 
         hide: row.get("hide"),
+        icon: row.get("icon"),
+        ha_icon: row.get("ha_icon"),
+        compact_sensors: row.get("compact_sensors"),
     }))
 }
 
+/// Set or reset (`None`) the custom emoji icon of a room.
+pub async fn set_room_icon(id: i64, icon: Option<&str>, pool: &SqlitePool) -> Result<()> {
+    sqlx::query("UPDATE rooms SET icon = ? WHERE id = ?")
+        .bind(icon)
+        .bind(id)
+        .execute(pool)
+        .await
+        .map_err(|e| anyhow::anyhow!("Failed to set room icon: {}", e))?;
+
+    Ok(())
+}
+
+/// Toggle the compact (two per row) sensor layout of a room.
+pub async fn toggle_compact_sensors(id: i64, pool: &SqlitePool) -> Result<()> {
+    sqlx::query("UPDATE rooms SET compact_sensors = NOT compact_sensors WHERE id = ?")
+        .bind(id)
+        .execute(pool)
+        .await
+        .map_err(|e| anyhow::anyhow!("Failed to toggle compact sensors: {}", e))?;
+
+    Ok(())
+}
+
+/// Move a room one step up or down in the list (hidden rooms included).
+pub async fn move_room(id: i64, up: bool, pool: &SqlitePool) -> Result<()> {
+    let mut order: Vec<i64> = get_all_rooms(pool).await?.into_iter().map(|r| r.id).collect();
+
+    let Some(index) = order.iter().position(|&r| r == id) else {
+        return Ok(());
+    };
+    let target = match up {
+        true if index > 0 => index - 1,
+        false if index + 1 < order.len() => index + 1,
+        _ => return Ok(()),
+    };
+
+    order.swap(index, target);
+    reorder_rooms(&order, pool).await
+}
+
+/// Rewrite room positions to match `order` (room IDs).
+pub async fn reorder_rooms(order: &[i64], pool: &SqlitePool) -> Result<()> {
+    let mut tx = pool.begin().await?;
+
+    for (position, id) in order.iter().enumerate() {
+        sqlx::query("UPDATE rooms SET position = ? WHERE id = ?")
+            .bind(position as i64)
+            .bind(id)
+            .execute(&mut *tx)
+            .await?;
+    }
+
+    tx.commit().await?;
+    Ok(())
+}
+
 /// Hide a room by setting its hide flag to true.
 pub async fn hide_room(area: &str, pool: &SqlitePool) -> Result<()> {
     sqlx::query(
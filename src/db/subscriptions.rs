@@ -21,12 +21,102 @@ pub async fn is_hidden(entity_id: &str, pool: &SqlitePool) -> anyhow::Result<boo
     Ok(hide_value.map_or(false, |val| val != 0))
 }
 
-pub async fn get_subscribers(entity_id: &str, pool: &SqlitePool) -> anyhow::Result<Vec<i64>> {
-    let rows = sqlx::query_as::<_, (i64,)>("SELECT user_id FROM subscriptions WHERE entity_id = ?")
+/// Effective visibility for a user: the user's own override if set,
+/// otherwise the admin-defined default from `hidden_entities`.
+pub async fn is_hidden_for(user_id: u64, entity_id: &str, pool: &SqlitePool) -> anyhow::Result<bool> {
+    let hide_value: Option<i64> = sqlx::query_scalar(
+        "SELECT COALESCE(
+            (SELECT hide FROM user_hidden_entities WHERE user_id = ?1 AND entity_id = ?2),
+            (SELECT hide FROM hidden_entities WHERE entity_id = ?2)
+        )"
+    )
+    .bind(user_id as i64)
+    .bind(entity_id)
+    .fetch_one(pool)
+    .await?;
+
+    Ok(hide_value.is_some_and(|val| val != 0))
+}
+
+/// Entities hidden for a user in one query: the same rules as `is_hidden_for`.
+pub async fn get_hidden_for(user_id: u64, pool: &SqlitePool) -> anyhow::Result<std::collections::HashSet<String>> {
+    let rows: Vec<(String,)> = sqlx::query_as(
+        "SELECT h.entity_id FROM hidden_entities h
+         WHERE h.hide != 0
+           AND NOT EXISTS (SELECT 1 FROM user_hidden_entities u WHERE u.user_id = ?1 AND u.entity_id = h.entity_id)
+         UNION
+         SELECT entity_id FROM user_hidden_entities WHERE user_id = ?1 AND hide != 0"
+    )
+    .bind(user_id as i64)
+    .fetch_all(pool)
+    .await?;
+
+    Ok(rows.into_iter().map(|(entity_id,)| entity_id).collect())
+}
+
+/// Toggles visibility for one user only. An override equal to the default is removed,
+/// so the user inherits later changes of the default again.
+/// Returns true if the entity is now hidden for the user.
+pub async fn toggle_hidden_for(user_id: u64, entity_id: &str, pool: &SqlitePool) -> anyhow::Result<bool> {
+    let new_hide = !is_hidden_for(user_id, entity_id, pool).await?;
+
+    if new_hide == is_hidden(entity_id, pool).await? {
+        sqlx::query("DELETE FROM user_hidden_entities WHERE user_id = ? AND entity_id = ?")
+            .bind(user_id as i64)
+            .bind(entity_id)
+            .execute(pool)
+            .await?;
+    } else {
+        sqlx::query(
+            "INSERT INTO user_hidden_entities (user_id, entity_id, hide) VALUES (?, ?, ?)
+             ON CONFLICT(user_id, entity_id) DO UPDATE SET hide = excluded.hide"
+        )
+        .bind(user_id as i64)
+        .bind(entity_id)
+        .bind(new_hide as i64)
+        .execute(pool)
+        .await?;
+    }
+
+    Ok(new_hide)
+}
+
+/// Returns subscribers of an entity together with the camera linked to their subscription.
+pub async fn get_subscribers(entity_id: &str, pool: &SqlitePool) -> anyhow::Result<Vec<(i64, Option<String>)>> {
+    let rows = sqlx::query_as::<_, (i64, Option<String>)>(
+        "SELECT user_id, camera_entity_id FROM subscriptions WHERE entity_id = ?"
+    )
         .bind(entity_id)
         .fetch_all(pool)
         .await?;
-    Ok(rows.into_iter().map(|r| r.0).collect())
+    Ok(rows)
+}
+
+pub async fn get_linked_camera(user_id: i64, entity_id: &str, pool: &SqlitePool) -> anyhow::Result<Option<String>> {
+    let camera: Option<Option<String>> = sqlx::query_scalar(
+        "SELECT camera_entity_id FROM subscriptions WHERE user_id = ? AND entity_id = ?"
+    )
+        .bind(user_id)
+        .bind(entity_id)
+        .fetch_optional(pool)
+        .await?;
+    Ok(camera.flatten())
+}
+
+/// Links a camera to an existing subscription (`None` unlinks it).
+pub async fn set_linked_camera(
+    user_id: i64,
+    entity_id: &str,
+    camera_entity_id: Option<&str>,
+    pool: &SqlitePool,
+) -> anyhow::Result<()> {
+    sqlx::query("UPDATE subscriptions SET camera_entity_id = ? WHERE user_id = ? AND entity_id = ?")
+        .bind(camera_entity_id)
+        .bind(user_id)
+        .bind(entity_id)
+        .execute(pool)
+        .await?;
+    Ok(())
 }
 
 /// Toggles subscription for user to entity. Returns true if now subscribed, false if unsubscribed.
@@ -73,7 +163,8 @@ pub async fn entity_exists(entity_id: &str, pool: &SqlitePool) -> anyhow::Result
     Ok(exists)
 }
 
-/// Toggles the hidden flag for an entity. If entity doesn't exist, creates it with hide=1.
+/// Toggles the admin-defined default ("hide for everyone"). If entity doesn't exist, creates it with hide=1.
+/// Per-user overrides are dropped, so the new default applies to all users.
 /// Returns true if entity is now hidden (hide=1), false if visible (hide=0).
 pub async fn toggle_hidden(
     entity_id: &str,
@@ -100,19 +191,29 @@ pub async fn toggle_hidden(
             .await?;
             
             log::info!("Toggled hidden: {} from {} to {}", entity_id, hide_val, new_hide);
+            clear_user_overrides(entity_id, pool).await?;
             Ok(new_hide != 0)
         }
         None => {
-            // Row doesn't exist: create with hide=0 (visible)
+            // Row doesn't exist (visible by default): create with hide=1
             sqlx::query(
-                "INSERT INTO hidden_entities (entity_id, hide) VALUES (?, 0)"
+                "INSERT INTO hidden_entities (entity_id, hide) VALUES (?, 1)"
             )
             .bind(entity_id)
             .execute(pool)
             .await?;
-            
-            log::info!("Created unhidden: {}", entity_id);
-            Ok(false) // Now visible
+
+            log::info!("Created hidden: {}", entity_id);
+            clear_user_overrides(entity_id, pool).await?;
+            Ok(true) // Now hidden
         }
     }
 }
+
+async fn clear_user_overrides(entity_id: &str, pool: &SqlitePool) -> anyhow::Result<()> {
+    sqlx::query("DELETE FROM user_hidden_entities WHERE entity_id = ?")
+        .bind(entity_id)
+        .execute(pool)
+        .await?;
+    Ok(())
+}
@@ -0,0 +1,19 @@
+//! Database module for de-duplicating admin notifications about available updates.
+
+use anyhow::Result;
+use sqlx::SqlitePool;
+
+/// Records that admins were notified about `version` of the update entity.
+/// Returns `true` only the first time for a given entity and version.
+pub async fn mark_notified(entity_id: &str, version: &str, pool: &SqlitePool) -> Result<bool> {
+    let inserted = sqlx::query(
+        "INSERT OR IGNORE INTO update_notifications (entity_id, version) VALUES (?, ?)",
+    )
+    .bind(entity_id)
+    .bind(version)
+    .execute(pool)
+    .await?
+    .rows_affected();
+
+    Ok(inserted > 0)
+}
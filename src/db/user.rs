@@ -91,4 +91,18 @@ pub async fn save_user_session(
             Err(e) => log::error!("Critical error saving session to disk: {}", e),
         }
     });
-}
\ No newline at end of file
+}
+/// Retrieves IDs of users marked as admins.
+///
+/// # Arguments
+/// * `pool` - Database connection pool
+///
+/// # Returns
+/// * `Result<Vec<i64>>` - IDs of admin users
+pub async fn get_admin_ids(pool: &SqlitePool) -> Result<Vec<i64>> {
+    let rows = sqlx::query_scalar::<_, i64>("SELECT id FROM users WHERE is_admin = 1")
+        .fetch_all(pool)
+        .await?;
+
+    Ok(rows)
+}
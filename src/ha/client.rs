@@ -15,6 +15,7 @@ struct HaHistoryItemFull {
 
 pub struct HAClient {
     url: String,
+    token: String,
     client: Client,
 }
 
@@ -35,6 +36,7 @@ impl HAClient {
 
         Self {
             url: url.trim_end_matches('/').to_string(),
+            token,
             client: Client::builder()
                 .default_headers(headers)
                 .timeout(std::time::Duration::from_secs(timeout_secs))
@@ -63,8 +65,11 @@ impl HAClient {
         res.json::<T>().await.context("Failed to parse template response")
     }
 
-    pub async fn fetch_rooms(&self) -> Result<Vec<Room>> {
-        self.post_template(super::templates::ROOMS_TEMPLATE).await
+    /// Комнаты HA с сущностями из переданных доменов.
+    pub async fn fetch_rooms(&self, domains: &[&str]) -> Result<Vec<Room>> {
+        let template = super::templates::ROOMS_TEMPLATE
+            .replace("__DOMAINS__", &serde_json::to_string(domains)?);
+        self.post_template(&template).await
     }
 
     pub async fn fetch_history(
@@ -136,10 +141,11 @@ impl HAClient {
               {{%- set items = {} -%}}
               {{%- for eid in items -%}}
               {{
-                "entity_id": "{{{{ eid }}}}",
-                "state": "{{{{ states(eid) }}}}",
-                "friendly_name": "{{{{ state_attr(eid, 'friendly_name') | default('', true) | replace('"', '\\"') }}}}",
-                "device_class": "{{{{ state_attr(eid, 'device_class') | default('', true) }}}}"
+                "entity_id": {{{{ eid | tojson }}}},
+                "state": {{{{ states(eid) | tojson }}}},
+                "friendly_name": {{{{ state_attr(eid, 'friendly_name') | default('', true) | tojson }}}},
+                "device_class": {{{{ state_attr(eid, 'device_class') | default('', true) | tojson }}}},
+                "attributes": {{{{ states[eid].attributes | default({{}}, true) | tojson }}}}
               }} {{{{ "," if not loop.last }}}}
               {{%- endfor -%}}
             ]"#,
@@ -149,6 +155,54 @@ impl HAClient {
         self.post_template(&template).await
     }
 
+    /// Текущий кадр камеры через HA camera proxy (JPEG).
+    pub async fn fetch_camera_snapshot(&self, entity_id: &str) -> Result<Vec<u8>> {
+        let url = format!("{}/api/camera_proxy/{}", self.url, entity_id);
+        let res = self.client.get(&url).send().await.context("HA Camera proxy failure")?;
+
+        if !res.status().is_success() {
+            return Err(anyhow::anyhow!("HA API returned error: {}", res.status()));
+        }
+
+        Ok(res.bytes().await.context("Failed to read camera snapshot")?.to_vec())
+    }
+
+    /// MJPEG-поток камеры для внешних потребителей (FFmpeg) по подписанному пути.
+    /// Подпись живет `expires_s` секунд, поэтому долгоживущий токен не попадает в аргументы процесса.
+    pub async fn signed_camera_stream_url(&self, entity_id: &str, expires_s: u64) -> Result<String> {
+        let result = self.ws_command(json!({
+            "type": "auth/sign_path",
+            "path": format!("/api/camera_proxy_stream/{}", entity_id),
+            "expires": expires_s,
+        })).await?;
+
+        let path = result.get("path").and_then(|p| p.as_str())
+            .ok_or_else(|| anyhow::anyhow!("auth/sign_path returned no path"))?;
+
+        Ok(format!("{}{}", self.url, path))
+    }
+
+    /// Все сущности домена (без привязки к комнатам), вместе с атрибутами и временем изменения.
+    pub async fn fetch_states_by_domain(&self, domain: &str) -> Result<Vec<Entity>> {
+        let template = format!(
+            r#"[
+              {{%- for st in states.{} -%}}
+              {{
+                "entity_id": {{{{ st.entity_id | tojson }}}},
+                "state": {{{{ st.state | tojson }}}},
+                "friendly_name": {{{{ st.attributes.friendly_name | default('', true) | tojson }}}},
+                "device_class": {{{{ st.attributes.device_class | default('', true) | tojson }}}},
+                "attributes": {{{{ st.attributes | tojson }}}},
+                "last_changed": "{{{{ st.last_changed.isoformat() }}}}"
+              }} {{{{ "," if not loop.last }}}}
+              {{%- endfor -%}}
+            ]"#,
+            domain
+        );
+
+        self.post_template(&template).await
+    }
+
     pub async fn call_service(&self, domain: &str, service: &str, entity_id: &str) -> Result<()> {
         let url = format!("{}/api/services/{}/{}", self.url, domain, service);
         let res = self.client.post(&url)
@@ -162,6 +216,64 @@ impl HAClient {
         Ok(())
     }
 
+    /// Вызов сервиса с ответом (`?return_response`), например `weather.get_forecasts`.
+    /// Возвращает содержимое `service_response`.
+    pub async fn call_service_with_response(
+        &self,
+        domain: &str,
+        service: &str,
+        entity_id: &str,
+        data: serde_json::Value
+    ) -> Result<serde_json::Value> {
+        let url = format!("{}/api/services/{}/{}?return_response", self.url, domain, service);
+
+        let mut body = data;
+        body["entity_id"] = serde_json::json!(entity_id);
+
+        let res = self.client.post(&url)
+            .json(&body)
+            .send()
+            .await
+            .context("Failed to call HA service with response")?;
+
+        if !res.status().is_success() {
+            let status = res.status();
+            let body = res.text().await.unwrap_or_default();
+            return Err(anyhow::anyhow!("HA API Error {}: {}", status, body));
+        }
+
+        let mut value: serde_json::Value = res.json().await.context("Failed to parse service response")?;
+        Ok(value["service_response"].take())
+    }
+
+    /// Разовая команда WebSocket API (например, `trace/list`). Возвращает поле `result`.
+    pub async fn ws_command(&self, command: serde_json::Value) -> Result<serde_json::Value> {
+        super::ws_command::ws_command(&self.url, &self.token, command).await
+    }
+
+    /// Вызов сервиса без целевой сущности (например, `device_tracker.see`).
+    pub async fn call_service_with_payload(
+        &self,
+        domain: &str,
+        service: &str,
+        data: serde_json::Value
+    ) -> Result<()> {
+        let url = format!("{}/api/services/{}/{}", self.url, domain, service);
+
+        let res = self.client.post(&url)
+            .json(&data)
+            .send()
+            .await
+            .context("Failed to call HA service")?;
+
+        if !res.status().is_success() {
+            let status = res.status();
+            let body = res.text().await.unwrap_or_default();
+            return Err(anyhow::anyhow!("HA API Error {}: {}", status, body));
+        }
+        Ok(())
+    }
+
     pub async fn call_service_with_data(
         &self,
         domain: &str,
@@ -188,4 +300,65 @@ impl HAClient {
         }
         Ok(())
     }
-}
\ No newline at end of file
+}
+
+#[cfg(test)]
+mod tests {
+    use tokio::io::{AsyncReadExt, AsyncWriteExt};
+    use tokio::net::TcpListener;
+
+    use super::*;
+
+    /// Однократный HTTP-сервер: отдает `body` на первый запрос и возвращает тело запроса.
+    async fn serve_once(body: &'static str) -> (String, tokio::task::JoinHandle<String>) {
+        let listener = TcpListener::bind("127.0.0.1:0").await.unwrap();
+        let url = format!("http://{}", listener.local_addr().unwrap());
+
+        let handle = tokio::spawn(async move {
+            let (mut socket, _) = listener.accept().await.unwrap();
+            let mut request = Vec::new();
+            let mut buf = [0u8; 4096];
+            loop {
+                let n = socket.read(&mut buf).await.unwrap();
+                request.extend_from_slice(&buf[..n]);
+                let text = String::from_utf8_lossy(&request);
+                if let Some((head, rest)) = text.split_once("\r\n\r\n") {
+                    let length = head.lines()
+                        .find_map(|l| l.to_ascii_lowercase().strip_prefix("content-length:").map(|v| v.trim().parse::<usize>().unwrap()))
+                        .unwrap_or(0);
+                    if rest.len() >= length || n == 0 {
+                        break;
+                    }
+                }
+            }
+
+            let response = format!(
+                "HTTP/1.1 200 OK\r\nContent-Type: application/json\r\nContent-Length: {}\r\nConnection: close\r\n\r\n{}",
+                body.len(), body
+            );
+            socket.write_all(response.as_bytes()).await.unwrap();
+            String::from_utf8_lossy(&request).into_owned()
+        });
+
+        (url, handle)
+    }
+
+    #[tokio::test]
+    async fn test_fetch_states_by_ids_handles_quoted_state() {
+        // Так HA отрисовывает шаблон для input_text со значением `say "hi" \ bye`
+        let (url, request) = serve_once(
+            r#"[{"entity_id": "input_text.note", "state": "say \"hi\" \\ bye", "friendly_name": "Заметка \"дом\"", "device_class": "", "attributes": {}}]"#
+        ).await;
+        let client = HAClient::new(url, "token".into(), 5, 5);
+
+        let entities = client.fetch_states_by_ids(&["input_text.note".to_string()]).await.unwrap();
+
+        assert_eq!(entities[0].state, r#"say "hi" \ bye"#);
+        assert_eq!(entities[0].friendly_name.as_deref(), Some(r#"Заметка "дом""#));
+
+        // Строковые поля экранирует сам HA: без кавычек вокруг выражения
+        let request = request.await.unwrap();
+        assert!(request.contains("states(eid) | tojson"));
+        assert!(!request.contains(r#"\"{{ states(eid)"#));
+    }
+}
@@ -14,11 +14,12 @@ pub fn spawn_event_listener(
     url: String,
     token: String,
     cancel_token: CancellationToken,
-    tx: mpsc::Sender<super::models::NotifyEvent>) {
+    tx: mpsc::Sender<super::models::NotifyEvent>,
+    pn_tx: mpsc::Sender<super::models::PersistentNotification>) {
 
     tokio::spawn(async move {
         tokio::select! {
-            _ = start_event_listener(url, token, cancel_token.clone(), tx) => {
+            _ = start_event_listener(url, token, cancel_token.clone(), tx, pn_tx) => {
                 info!("Event listener finished.");
             }
             _ = cancel_token.cancelled() => {
@@ -32,7 +33,8 @@ async fn start_event_listener(
     ha_url: String,
     ha_token: String,
     cancel_token: CancellationToken,
-    tx: mpsc::Sender<super::models::NotifyEvent>
+    tx: mpsc::Sender<super::models::NotifyEvent>,
+    pn_tx: mpsc::Sender<super::models::PersistentNotification>
 ) {
     let ws_url = ha_url.replace("http", "ws").trim_end_matches('/').to_string() + "/api/websocket";
     let mut backoff = Duration::from_millis(500);
@@ -96,6 +98,12 @@ async fn start_event_listener(
                                 "type": "subscribe_events",
                                 "event_type": "state_changed"
                             }).to_string()))).await;
+
+                            id_counter += 1;
+                            let _ = write.send(Message::Text(Utf8Bytes::from(json!({
+                                "id": id_counter,
+                                "type": "persistent_notification/subscribe"
+                            }).to_string()))).await;
                         }
                         Some("event") => {
                             if v["event"]["event_type"] == "state_changed" {
@@ -108,11 +116,23 @@ async fn start_event_listener(
                                     old_state: data["old_state"]["state"].as_str().unwrap_or_default().to_string(),
                                     new_state: data["new_state"]["state"].as_str().unwrap_or_default().to_string(),
                                     friendly_name: data["new_state"]["attributes"]["friendly_name"].as_str().unwrap_or("Устройство").to_string(),
-                                    device_class: data["new_state"]["attributes"]["device_class"].as_str().map(String::from)
+                                    device_class: data["new_state"]["attributes"]["device_class"].as_str().map(String::from),
+                                    event_type: data["new_state"]["attributes"]["event_type"].as_str().map(String::from),
                                 };
 
                                 let _ = tx.send(event).await;
                             }
+                            // "current" приходит при каждой (пере)подписке - пересылаем только новые
+                            else if v["event"]["type"] == "added" {
+                                let Some(items) = v["event"]["notifications"].as_object() else { continue; };
+
+                                for item in items.values() {
+                                    match serde_json::from_value::<super::models::PersistentNotification>(item.clone()) {
+                                        Ok(notification) => { let _ = pn_tx.send(notification).await; }
+                                        Err(e) => debug!("Failed to parse persistent notification: {}", e),
+                                    }
+                                }
+                            }
                         }
                         _ => {}
                     }
@@ -8,6 +8,10 @@ pub struct Entity {
     pub state: String,
     pub device_class: Option<String>,
     pub friendly_name: Option<String>, 
+    #[serde(default)]
+    pub attributes: serde_json::Value,
+    #[serde(default)]
+    pub last_changed: Option<chrono::DateTime<chrono::Utc>>,
 }
 
 #[derive(Deserialize, Debug, Clone)]
@@ -23,5 +27,29 @@ pub struct NotifyEvent {
     pub old_state: String,
     pub new_state: String,
     pub friendly_name: String,
-    pub device_class: Option<String>
-}
\ No newline at end of file
+    pub device_class: Option<String>,
+    /// Тип события для event-сущностей (`attributes.event_type`).
+    pub event_type: Option<String>,
+}
+/// Проблема из раздела Repairs (`repairs/list_issues`).
+#[derive(Deserialize, Debug, Clone)]
+pub struct RepairIssue {
+    pub domain: String,
+    pub issue_id: String,
+    #[serde(default)]
+    pub severity: String,
+    #[serde(default)]
+    pub ignored: bool,
+}
+
+/// Постоянное уведомление HA (`persistent_notification`).
+#[derive(Deserialize, Debug, Clone)]
+pub struct PersistentNotification {
+    pub notification_id: String,
+    #[serde(default)]
+    pub title: Option<String>,
+    #[serde(default)]
+    pub message: String,
+    #[serde(default)]
+    pub created_at: Option<chrono::DateTime<chrono::Utc>>,
+}
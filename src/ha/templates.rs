@@ -1,3 +1,4 @@
+/// Список доменов подставляется вместо `__DOMAINS__` (см. `HAClient::fetch_rooms`).
 pub const ROOMS_TEMPLATE: &str = r#"
 [
   {%- set ns_room = namespace(first=true) -%}
@@ -6,23 +7,23 @@ pub const ROOMS_TEMPLATE: &str = r#"
     {%- set valid_entities = namespace(items=[]) -%}
     {%- for e in area_ents -%}
       {%- set d = e.split('.')[0] -%}
-      {%- if d in ['light', 'switch', 'sensor', 'binary_sensor', 'number', 'climate'] -%}
+      {%- if d in __DOMAINS__ -%}
         {%- set valid_entities.items = valid_entities.items + [e] -%}
       {%- endif -%}
     {%- endfor -%}
     {%- if valid_entities.items | length > 0 -%}
       {{ "," if not ns_room.first }}
       {
-        "id": "{{ a }}",
-        "name": "{{ area_name(a) | default(a, true) }}",
+        "id": {{ a | tojson }},
+        "name": {{ area_name(a) | default(a, true) | tojson }},
         "entities": [
           {%- for e in valid_entities.items -%}
             {
-              "entity_id": "{{ e }}",
-              "friendly_name": "{{ state_attr(e, 'friendly_name') | default('', true) | replace('"', '\\"') }}",
-              "name": "{{ state_attr(e, 'friendly_name') | default(e, true) | replace('"', '\\"') }}",
-              "state": "{{ states(e) }}",
-              "device_class": "{{ state_attr(e, 'device_class') | default('', true) }}"
+              "entity_id": {{ e | tojson }},
+              "friendly_name": {{ state_attr(e, 'friendly_name') | default('', true) | tojson }},
+              "name": {{ state_attr(e, 'friendly_name') | default(e, true) | tojson }},
+              "state": {{ states(e) | tojson }},
+              "device_class": {{ state_attr(e, 'device_class') | default('', true) | tojson }}
             }{{ "," if not loop.last }}
           {%- endfor -%}
         ]
@@ -0,0 +1,59 @@
+use anyhow::{anyhow, Context, Result};
+use futures_util::{SinkExt, StreamExt};
+use serde_json::{json, Value};
+use std::time::Duration;
+
+use tokio_tungstenite::{connect_async, tungstenite::protocol::Message};
+use tungstenite::Utf8Bytes;
+
+/// Таймаут на весь обмен: подключение, авторизация и ответ.
+const WS_COMMAND_TIMEOUT_S: u64 = 10;
+
+/// Разовая WS-команда (для API, которых нет в REST, например `trace/list`).
+/// Открывает отдельное соединение, чтобы не зависеть от слушателя событий.
+pub async fn ws_command(ha_url: &str, ha_token: &str, command: Value) -> Result<Value> {
+    tokio::time::timeout(
+        Duration::from_secs(WS_COMMAND_TIMEOUT_S),
+        run_command(ha_url, ha_token, command),
+    )
+        .await
+        .map_err(|_| anyhow!("HA WebSocket command timed out"))?
+}
+
+async fn run_command(ha_url: &str, ha_token: &str, mut command: Value) -> Result<Value> {
+    let ws_url = ha_url.replace("http", "ws").trim_end_matches('/').to_string() + "/api/websocket";
+    let (ws_stream, _) = connect_async(&ws_url).await.context("HA WebSocket connection failed")?;
+    let (mut write, mut read) = ws_stream.split();
+
+    command["id"] = json!(1);
+
+    while let Some(msg) = read.next().await {
+        let text = msg.context("HA WebSocket read failed")?;
+        let text = text.to_text().unwrap_or("");
+        if text.is_empty() {
+            continue;
+        }
+
+        let v: Value = serde_json::from_str(text).context("Invalid HA WebSocket message")?;
+
+        match v["type"].as_str() {
+            Some("auth_required") => {
+                write.send(Message::Text(Utf8Bytes::from(json!({"type": "auth", "access_token": ha_token}).to_string()))).await?;
+            }
+            Some("auth_ok") => {
+                write.send(Message::Text(Utf8Bytes::from(command.to_string()))).await?;
+            }
+            Some("auth_invalid") => return Err(anyhow!("HA WebSocket auth failed")),
+            Some("result") if v["id"] == 1 => {
+                let _ = write.close().await;
+                if v["success"].as_bool() == Some(true) {
+                    return Ok(v["result"].clone());
+                }
+                return Err(anyhow!("HA WebSocket command failed: {}", v["error"]));
+            }
+            _ => {}
+        }
+    }
+
+    Err(anyhow!("HA WebSocket closed before result"))
+}
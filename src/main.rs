@@ -22,6 +22,7 @@ mod options;
 mod bot;
 mod core;
 mod charts;
+mod video_engine;
 
 #[tokio::main]
 async fn main() -> Result<()> {
@@ -46,6 +47,8 @@ async fn main() -> Result<()> {
 
     let ha_client = Arc::new(ha::init(paths.ha_url.clone(), paths.ha_token.clone()));
 
+    let (video, clip_rx) = video_engine::VideoProcessor::new();
+
     let app_config = Arc::new(AppConfig {
         ha_client: ha_client.clone(),
         db: db_pool,
@@ -60,6 +63,12 @@ async fn main() -> Result<()> {
         name_aliases: DashMap::new(),
 
         state_aliases: DashMap::new(),
+
+        video,
+
+        confirmations: core::confirmations::StateConfirmations::new(),
+        undo: core::undo::UndoStore::default(),
+        search_queries: DashMap::new(),
     });
 
     info!("Load Backup sessions from database...");
@@ -69,9 +78,20 @@ async fn main() -> Result<()> {
         app_config.sessions.insert(uid as u64, crate::models::UserSession {
             last_menu_id: mid,
             current_context: context,
-            header_entities: std::collections::HashSet::new(), // Это можно тоже хранить в БД, если нужно
+            header_entities: Vec::new(),
         });
     }
+
+    match db::pinned_headers::get_all_pinned(&app_config.db).await {
+        Ok(pins) => {
+            for (uid, eid) in pins {
+                if let Some(mut session) = app_config.sessions.get_mut(&(uid as u64)) {
+                    session.header_entities.push(eid);
+                }
+            }
+        }
+        Err(e) => error!("Core: Failed to load pinned header entities: {}", e),
+    }
     info!("Restored {} action sessions.", app_config.sessions.len());
 
     match db::devices::get_all_display_names(&app_config.db).await {
@@ -90,7 +110,8 @@ async fn main() -> Result<()> {
     }
 
     let (tx, rx) = mpsc::channel::<ha::NotifyEvent>(100);
-    ha::spawn_event_listener(paths.ha_url.clone(), paths.ha_token.clone(), cancel_token.clone(), tx);
+    let (pn_tx, pn_rx) = mpsc::channel::<ha::PersistentNotification>(16);
+    ha::spawn_event_listener(paths.ha_url.clone(), paths.ha_token.clone(), cancel_token.clone(), tx, pn_tx);
 
     info!("✅ Run Dispatcher...");
 
@@ -113,6 +134,8 @@ async fn main() -> Result<()> {
 
     core::spawn_notification_processor(rx, _bot.clone(), app_config.clone(), cancel_token.clone());
     core::spawn_background_maintenance(_bot.clone(), app_config.clone(), cancel_token.clone());
+    core::persistent_notifications::spawn_persistent_notification_processor(pn_rx, _bot.clone(), app_config.clone(), cancel_token.clone());
+    video_engine::spawn_clip_worker(clip_rx, _bot.clone(), app_config.clone(), cancel_token.clone());
 
     let bot_task = dispatcher.dispatch();
 
@@ -1,6 +1,8 @@
-use std::collections::HashSet;
 use std::sync::Arc;
 use crate::ha::HAClient;
+use crate::video_engine::VideoProcessor;
+use crate::core::confirmations::StateConfirmations;
+use crate::core::undo::UndoStore;
 use dashmap::DashMap;
 use serde::Deserialize;
 
@@ -8,7 +10,8 @@ use serde::Deserialize;
 pub struct UserSession {
     pub last_menu_id: i32,
     pub current_context: String,
-    pub header_entities: HashSet<String>,
+    /// Закрепленные сущности (избранное) в порядке пользователя.
+    pub header_entities: Vec<String>,
 }
 
 pub struct AppConfig {
@@ -27,6 +30,13 @@ pub struct AppConfig {
 
     pub name_aliases: DashMap<String, String>,
     pub state_aliases: DashMap<String, std::collections::HashMap<String, String>>,
+
+    pub video: VideoProcessor,
+
+    pub confirmations: StateConfirmations,
+    pub undo: UndoStore,
+    /// Последний поисковый запрос пользователя: в callback data он не помещается.
+    pub search_queries: DashMap<u64, String>,
 }
 
 #[derive(Deserialize, Debug, Clone)]
@@ -0,0 +1,143 @@
+use std::path::Path;
+use std::process::Stdio;
+use std::sync::Arc;
+use std::time::Duration;
+
+use anyhow::{anyhow, Context, Result};
+use log::{error, info, warn};
+use teloxide::prelude::*;
+use teloxide::types::InputFile;
+use tokio::process::Command;
+use tokio::sync::mpsc;
+use tokio_util::sync::CancellationToken;
+
+use crate::models::AppConfig;
+
+/// Длительность клипа по кнопке "Записать".
+pub const CLIP_DURATION_S: u64 = 10;
+
+/// Максимум заданий, ожидающих обработки. Сверх лимита запросы отклоняются.
+const QUEUE_CAPACITY: usize = 4;
+
+/// Срок действия подписанной ссылки на поток: FFmpeg подключается сразу после ее получения.
+const STREAM_SIGN_EXPIRES_S: u64 = 30;
+
+/// Запас времени на подключение к потоку и кодирование сверх длительности клипа.
+const FFMPEG_TIMEOUT_S: u64 = CLIP_DURATION_S + 50;
+
+#[derive(Debug, Clone)]
+pub struct ClipJob {
+    pub user_id: u64,
+    pub entity_id: String,
+    pub title: String,
+}
+
+/// Входная точка видеоподсистемы: ограниченная очередь заданий на запись клипов.
+/// Задания обрабатывает один воркер, поэтому одновременно работает не более одного FFmpeg.
+pub struct VideoProcessor {
+    tx: mpsc::Sender<ClipJob>,
+}
+
+impl VideoProcessor {
+    pub fn new() -> (Self, mpsc::Receiver<ClipJob>) {
+        let (tx, rx) = mpsc::channel(QUEUE_CAPACITY);
+        (Self { tx }, rx)
+    }
+
+    /// Ставит задание в очередь без ожидания. Ошибка, если очередь заполнена.
+    pub fn enqueue(&self, job: ClipJob) -> Result<()> {
+        self.tx.try_send(job).map_err(|e| match e {
+            mpsc::error::TrySendError::Full(_) => anyhow!("Очередь записи переполнена, попробуйте позже"),
+            mpsc::error::TrySendError::Closed(_) => anyhow!("Видеоподсистема остановлена"),
+        })
+    }
+}
+
+pub fn spawn_clip_worker(
+    mut rx: mpsc::Receiver<ClipJob>,
+    bot: Bot,
+    config: Arc<AppConfig>,
+    cancel_token: CancellationToken,
+) {
+    info!("Video: Clip worker started");
+
+    tokio::spawn(async move {
+        loop {
+            tokio::select! {
+                Some(job) = rx.recv() => {
+                    let chat_id = ChatId(job.user_id as i64);
+                    if let Err(e) = process_clip(&bot, &config, &job).await {
+                        error!("Video: Clip for {} failed: {:#}", job.entity_id, e);
+                        let _ = bot.send_message(chat_id, format!("⚠️ Не удалось записать клип «{}»", job.title)).await;
+                    }
+                }
+                _ = cancel_token.cancelled() => {
+                    info!("Video: Clip worker shutting down");
+                    break;
+                }
+            }
+        }
+    });
+}
+
+async fn process_clip(bot: &Bot, config: &Arc<AppConfig>, job: &ClipJob) -> Result<()> {
+    let output = std::env::temp_dir().join(format!(
+        "clip_{}_{}.mp4",
+        job.user_id,
+        chrono::Utc::now().timestamp_millis()
+    ));
+
+    let result = async {
+        record_clip(config, &job.entity_id, &output).await?;
+
+        bot.send_video(ChatId(job.user_id as i64), InputFile::file(&output))
+            .caption(format!("🎥 {} • {}с", job.title, CLIP_DURATION_S))
+            .supports_streaming(true)
+            .await
+            .context("Telegram send_video failed")?;
+
+        Ok(())
+    }.await;
+
+    let _ = tokio::fs::remove_file(&output).await;
+    result
+}
+
+/// Снимает поток камеры через HA и перекодирует в совместимый с Telegram H.264 MP4.
+async fn record_clip(config: &Arc<AppConfig>, entity_id: &str, output: &Path) -> Result<()> {
+    let stream_url = config.ha_client.signed_camera_stream_url(entity_id, STREAM_SIGN_EXPIRES_S).await
+        .context("Failed to sign camera stream path")?;
+    let duration = CLIP_DURATION_S.to_string();
+
+    let child = Command::new("ffmpeg")
+        .args(["-hide_banner", "-loglevel", "error", "-nostats", "-y"])
+        .args(["-i", &stream_url])
+        .args(["-t", &duration, "-an"])
+        .args(["-c:v", "libx264", "-preset", "veryfast", "-crf", "28"])
+        .args(["-pix_fmt", "yuv420p", "-vf", "scale=trunc(iw/2)*2:trunc(ih/2)*2"])
+        .args(["-movflags", "+faststart", "-f", "mp4"])
+        .arg(output)
+        .stdin(Stdio::null())
+        .stdout(Stdio::null())
+        .stderr(Stdio::piped())
+        .kill_on_drop(true)
+        .spawn()
+        .context("Failed to start ffmpeg (is it installed?)")?;
+
+    // stderr читается параллельно с ожиданием: иначе заполненный pipe блокирует FFmpeg.
+    // По таймауту future с процессом уничтожается, и `kill_on_drop` завершает FFmpeg.
+    let run = match tokio::time::timeout(Duration::from_secs(FFMPEG_TIMEOUT_S), child.wait_with_output()).await {
+        Ok(run) => run.context("ffmpeg wait failed")?,
+        Err(_) => {
+            warn!("Video: ffmpeg timeout for {}, killing", entity_id);
+            return Err(anyhow!("ffmpeg timed out after {}s", FFMPEG_TIMEOUT_S));
+        }
+    };
+
+    if !run.status.success() {
+        let details = String::from_utf8_lossy(&run.stderr);
+        return Err(anyhow!("ffmpeg exited with {}: {}", run.status, details.trim()));
+    }
+
+    Ok(())
+}